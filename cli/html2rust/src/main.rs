@@ -12,7 +12,7 @@ fn raw_string_escape(input: &str) -> (String, String) {
         .map(|found| found.as_str())
         .collect::<HashSet<_>>();
 
-    if quote_kinds.len() == 0 {
+    if quote_kinds.is_empty() {
         return (String::from(r#"""#), String::from(r#"""#));
     }
 
@@ -39,14 +39,12 @@ fn recurse_elements(nodes: Vec<Node>, prefix: Option<&str>) -> String {
                     output.push_str(prefix);
                 }
 
-                output.push('{');
                 output.push_str(&quotes.0);
                 output.push_str(&text);
                 output.push_str(&quotes.1);
-                output.push_str("}");
 
                 if prefix.is_some() {
-                    output.push_str("\n");
+                    output.push('\n');
                 }
             }
             Node::Element(element) => {
@@ -54,7 +52,7 @@ fn recurse_elements(nodes: Vec<Node>, prefix: Option<&str>) -> String {
                     output.push_str(prefix);
                 }
 
-                output.push_str("<");
+                output.push('<');
                 output.push_str(&element.name);
 
                 if let Some(id) = element.id {
@@ -80,7 +78,7 @@ fn recurse_elements(nodes: Vec<Node>, prefix: Option<&str>) -> String {
                         output.push_str(&quotes.0);
                         output.push_str(&value);
                         output.push_str(&quotes.1);
-                        output.push_str("}");
+                        output.push('}');
                     } else {
                         output.push_str("={true}");
                     }
@@ -91,14 +89,14 @@ fn recurse_elements(nodes: Vec<Node>, prefix: Option<&str>) -> String {
                 if let ElementVariant::Void = element.variant {
                     output.push_str(" />");
                 } else {
-                    output.push_str(">");
+                    output.push('>');
                 }
 
                 if prefix.is_some() {
-                    output.push_str("\n");
+                    output.push('\n');
                 }
 
-                let new_prefix = prefix.clone().map(|prefix| format!("{prefix}    "));
+                let new_prefix = prefix.map(|prefix| format!("{prefix}    "));
                 output.push_str(&recurse_elements(element.children, new_prefix.as_deref()));
 
                 if let ElementVariant::Normal = element.variant {
@@ -108,32 +106,25 @@ fn recurse_elements(nodes: Vec<Node>, prefix: Option<&str>) -> String {
 
                     output.push_str("</");
                     output.push_str(&element.name);
-                    output.push_str(">");
+                    output.push('>');
 
                     if prefix.is_some() {
-                        output.push_str("\n");
+                        output.push('\n');
                     }
                 }
             }
             Node::Comment(comment) => {
-                let indented_comments = comment
-                    .lines()
-                    .map(|line| {
-                        if let Some(prefix) = prefix {
-                            format!("{prefix}// {line}").trim_end().to_string()
-                        } else {
-                            format!("// {line}").trim_end().to_string()
-                        }
-                    })
-                    .collect::<Vec<String>>();
-
-                let indented_comment = if prefix.is_some() {
-                    indented_comments.join("\n")
-                } else {
-                    indented_comments.join("")
-                };
+                let quotes = raw_string_escape(&comment);
+
+                if let Some(prefix) = prefix {
+                    output.push_str(prefix);
+                }
 
-                output.push_str(&indented_comment);
+                output.push_str("<!-- ");
+                output.push_str(&quotes.0);
+                output.push_str(&comment);
+                output.push_str(&quotes.1);
+                output.push_str(" -->");
 
                 if prefix.is_some() {
                     output.push('\n');
@@ -172,18 +163,12 @@ fn main() {
     }
 
     let function_declaration = format!(
-        "pub fn {}() -> Result<String, core::fmt::Error> {{\n    html_codegen::html! {{\n",
+        "pub fn {}() -> Result<String, html_codegen::RenderError> {{\n    html_codegen::html! {{\n",
         args.function_name.as_deref().unwrap_or("html")
     );
     output.push_str(function_declaration.as_str());
 
-    if html
-        .children
-        .iter()
-        .filter(|child| matches!(child, &Node::Text(_) | &Node::Element(_)))
-        .count()
-        != 1
-    {
+    if html.children.len() != 1 {
         if args.whitespace {
             output.push_str("        <>\n");
             output.push_str(&recurse_elements(html.children, Some("            ")));
@@ -193,14 +178,12 @@ fn main() {
             output.push_str(&recurse_elements(html.children, None));
             output.push_str("</>");
         }
+    } else if args.whitespace {
+        output.push_str(&recurse_elements(html.children, Some("        ")));
     } else {
-        if args.whitespace {
-            output.push_str(&recurse_elements(html.children, Some("        ")));
-        } else {
-            output.push_str("        <>");
-            output.push_str(&recurse_elements(html.children, None));
-            output.push_str("\n");
-        }
+        output.push_str("        <>");
+        output.push_str(&recurse_elements(html.children, None));
+        output.push('\n');
     }
     output.push_str("    }\n}");
 
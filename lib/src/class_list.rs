@@ -0,0 +1,173 @@
+//! The class list attribute value
+
+use crate::{ToAttribute, AV};
+use std::borrow::Cow;
+
+/// A deduplicated list of CSS classes, rendered as a single space-joined
+/// `class` attribute
+///
+/// This is what `class:name={condition}` directives of `rsx!` expand into.
+/// Any value implementing [`ToClasses`] can be added: static class strings
+/// (which may contain several whitespace-separated classes), `Option`s,
+/// `(class, bool)` pairs and collections of those.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen_macros::html;
+/// use html_codegen::ClassList;
+///
+/// let is_active = true;
+/// let size: Option<&str> = None;
+///
+/// let classes = ClassList::new()
+///     .with("btn btn-primary")
+///     .with(size)
+///     .with(("active", is_active))
+///     .with(["disabled", "btn"].map(|class| (class, class != "disabled")));
+///
+/// let result = html! { <button class={classes}>{"Save"}</button> }.unwrap();
+///
+/// assert_eq!(result, r#"<button class="btn btn-primary active">Save</button>"#);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClassList<'a> {
+    classes: Vec<Cow<'a, str>>,
+}
+
+impl<'a> ClassList<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `classes`, returning the list
+    pub fn with(mut self, classes: impl ToClasses<'a>) -> Self {
+        self.add(classes);
+        self
+    }
+
+    /// Adds `classes`, skipping the ones already in the list
+    pub fn add(&mut self, classes: impl ToClasses<'a>) {
+        classes.add_to(self);
+    }
+
+    /// Adds a single class name, unless it is empty or already in the list
+    pub fn push(&mut self, class: impl Into<Cow<'a, str>>) {
+        let class = class.into();
+        if !class.is_empty() && !self.classes.contains(&class) {
+            self.classes.push(class);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.classes.iter().map(|class| class.as_ref())
+    }
+}
+
+impl<'a, T: ToClasses<'a>> Extend<T> for ClassList<'a> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for classes in iter {
+            self.add(classes);
+        }
+    }
+}
+
+impl<'a, T: ToClasses<'a>> FromIterator<T> for ClassList<'a> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+/// Renders the classes joined by spaces, or omits the attribute when empty
+impl<'a> ToAttribute<'a> for ClassList<'a> {
+    fn from_value(self) -> AV<'a> {
+        if self.is_empty() {
+            AV::None
+        } else {
+            AV::Some(Cow::Owned(self.iter().collect::<Vec<_>>().join(" ")))
+        }
+    }
+}
+
+/// A value that contributes zero or more classes to a [`ClassList`]
+pub trait ToClasses<'a> {
+    fn add_to(self, list: &mut ClassList<'a>);
+}
+
+impl<'a> ToClasses<'a> for &'a str {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        for class in self.split_whitespace() {
+            list.push(class);
+        }
+    }
+}
+
+impl<'a> ToClasses<'a> for &'a String {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        self.as_str().add_to(list)
+    }
+}
+
+impl<'a> ToClasses<'a> for String {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        if self.contains(char::is_whitespace) {
+            for class in self.split_whitespace() {
+                list.push(class.to_string());
+            }
+        } else {
+            list.push(self);
+        }
+    }
+}
+
+impl<'a> ToClasses<'a> for Cow<'a, str> {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        match self {
+            Cow::Borrowed(classes) => classes.add_to(list),
+            Cow::Owned(classes) => classes.add_to(list),
+        }
+    }
+}
+
+impl<'a> ToClasses<'a> for ClassList<'a> {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        for class in self.classes {
+            list.push(class);
+        }
+    }
+}
+
+impl<'a, T: ToClasses<'a>> ToClasses<'a> for Option<T> {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        if let Some(classes) = self {
+            classes.add_to(list);
+        }
+    }
+}
+
+/// Adds the classes only when the condition is `true`
+impl<'a, T: ToClasses<'a>> ToClasses<'a> for (T, bool) {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        let (classes, condition) = self;
+        if condition {
+            classes.add_to(list);
+        }
+    }
+}
+
+impl<'a, T: ToClasses<'a>> ToClasses<'a> for Vec<T> {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        list.extend(self);
+    }
+}
+
+impl<'a, T: ToClasses<'a>, const N: usize> ToClasses<'a> for [T; N] {
+    fn add_to(self, list: &mut ClassList<'a>) {
+        list.extend(self);
+    }
+}
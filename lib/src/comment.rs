@@ -0,0 +1,86 @@
+//! The comment component
+
+use crate::{Render, RenderRef};
+use std::fmt::{Result, Write};
+
+/// An HTML comment
+///
+/// The text is escaped so it can't close the comment early or open a nested
+/// one: `--` sequences are split by a space, which breaks up `-->`, `--!>`
+/// and `<!--`, and a space is written before a leading `>` or `->` and
+/// after a trailing `-`. Anything else is written as it is, so conditional
+/// comments like `[if IE]>...<![endif]` keep working. `<!-- {expr} -->` in
+/// `rsx!` expands into this component, while literal `<!-- "text" -->`
+/// comments are written as they are.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen_macros::html;
+/// use html_codegen::Comment;
+///
+/// let build = "42 --> <script>";
+/// let condition = "[if IE]><p>Upgrade</p><![endif]";
+///
+/// let result = html! {
+///     <>
+///         <!-- " License: MIT " -->
+///         <Comment text={format!(" build {build} ")} />
+///         <Comment text={condition} />
+///     </>
+/// }.unwrap();
+///
+/// assert_eq!(
+///     result,
+///     "<!-- License: MIT --><!-- build 42 - -> <script> --><!--[if IE]><p>Upgrade</p><![endif]-->"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Comment<T: AsRef<str>> {
+    pub text: T,
+}
+
+/// Renders `<!--text-->`, with `text` escaped
+impl<T: AsRef<str>> Render for Comment<T> {
+    fn render_into<W: Write>(self, writer: &mut W) -> Result {
+        self.render_ref(writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.size_hint_ref()
+    }
+}
+
+impl<T: AsRef<str>> RenderRef for Comment<T> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        writer.write_str("<!--")?;
+        escape_comment(self.text.as_ref(), writer)?;
+        writer.write_str("-->")
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        "<!---->".len() + self.text.as_ref().len()
+    }
+}
+
+/// Escapes comment text, so it can't end the comment or open a nested one.
+fn escape_comment<W: Write>(text: &str, writer: &mut W) -> Result {
+    // `<!-->` and `<!--->` are complete comments
+    if text.starts_with('>') || text.starts_with("->") {
+        writer.write_char(' ')?;
+    }
+
+    let mut previous = None;
+    for c in text.chars() {
+        match c {
+            '-' if previous == Some('-') => writer.write_str(" -")?,
+            c => writer.write_char(c)?,
+        };
+        previous = Some(c);
+    }
+
+    if previous == Some('-') {
+        writer.write_char(' ')?;
+    }
+
+    Ok(())
+}
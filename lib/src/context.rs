@@ -0,0 +1,108 @@
+//! Values provided to every component rendered under a [`Provide`]
+//!
+//! Rather than passing request data like the current user through the props
+//! of every component on the way, an ancestor provides it once, and the
+//! components that need it read it with [`use_context`]:
+//!
+//! ```rust
+//! # use pretty_assertions::assert_eq;
+//! use html_codegen::{component, html, rsx, use_context, Provide};
+//!
+//! #[derive(Clone)]
+//! struct CurrentUser(&'static str);
+//!
+//! #[component]
+//! fn Badge() {
+//!     let user = use_context::<CurrentUser>().map(|user| user.0);
+//!     rsx! { <span>{user.unwrap_or("Guest")}</span> }
+//! }
+//!
+//! #[component]
+//! fn Nav() {
+//!     rsx! { <nav><Badge /></nav> }
+//! }
+//!
+//! let signed_in = html! {
+//!     <Provide value={CurrentUser("Gal")}>
+//!         <Nav />
+//!     </Provide>
+//! }.unwrap();
+//!
+//! assert_eq!(signed_in, "<nav><span>Gal</span></nav>");
+//! assert_eq!(html! { <Nav /> }.unwrap(), "<nav><span>Guest</span></nav>");
+//! ```
+//!
+//! Context is only available while rendering, so it has to be read in the
+//! body of a component rather than in an `rsx!` block that creates it.
+
+use crate::{Render, RenderRef};
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt::{Result, Write};
+use std::rc::Rc;
+
+thread_local! {
+    /// The values provided by the components being rendered, innermost last
+    static PROVIDED: RefCell<Vec<Rc<dyn Any>>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Provides `value` to the components rendered in its children
+///
+/// A value provided further down the tree shadows one of the same type
+/// provided above it.
+#[derive(Debug, Clone)]
+pub struct Provide<V: 'static, T: Render> {
+    pub value: V,
+    pub children: T,
+}
+
+impl<V: 'static, T: Render> Render for Provide<V, T> {
+    fn render_into<W: Write>(self, writer: &mut W) -> Result {
+        PROVIDED.with(|provided| provided.borrow_mut().push(Rc::new(self.value)));
+        let _provided = Provided;
+        self.children.render_into(writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.children.size_hint()
+    }
+}
+
+/// Provides a clone of `value`
+impl<V: Clone + 'static, T: RenderRef + Render> RenderRef for Provide<V, T> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        PROVIDED.with(|provided| provided.borrow_mut().push(Rc::new(self.value.clone())));
+        let _provided = Provided;
+        self.children.render_ref(writer)
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.children.size_hint_ref()
+    }
+}
+
+/// Removes the value of a [`Provide`] once its children are rendered, even
+/// if rendering them panics
+struct Provided;
+
+impl Drop for Provided {
+    fn drop(&mut self) {
+        PROVIDED.with(|provided| provided.borrow_mut().pop());
+    }
+}
+
+/// Reads the innermost value of type `T` provided by an ancestor, or `None`
+/// if no ancestor provides one
+///
+/// The value is cloned, so large values are best provided behind an
+/// [`Rc`](std::rc::Rc).
+pub fn use_context<T: Clone + 'static>() -> Option<T> {
+    PROVIDED.with(|provided| {
+        provided
+            .borrow()
+            .iter()
+            .rev()
+            .find_map(|value| value.downcast_ref::<T>())
+            .cloned()
+    })
+}
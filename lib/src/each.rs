@@ -0,0 +1,71 @@
+//! The each component
+
+use crate::{Render, RenderRef};
+use std::fmt::{Result, Write};
+
+/// Renders every item of an iterator, without collecting it first
+///
+/// This is what `for` children of `rsx!` expand into.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen_macros::html;
+/// let animals = ["Mouse", "Rat", "Hamster"];
+///
+/// let result = html! {
+///     <ol>
+///         for index, animal in animals {
+///             <li value={index + 1}>{animal}</li>
+///         }
+///     </ol>
+/// }.unwrap();
+///
+/// assert_eq!(
+///     result,
+///     r#"<ol><li value="1">Mouse</li><li value="2">Rat</li><li value="3">Hamster</li></ol>"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Each<I, F> {
+    pub items: I,
+    pub render_item: F,
+}
+
+impl<I, F> Each<I, F> {
+    pub fn new<R>(items: I, render_item: F) -> Self
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> R,
+    {
+        Self { items, render_item }
+    }
+}
+
+/// Renders the result of `render_item` for every item in `items`
+impl<I, F, R> Render for Each<I, F>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item) -> R,
+    R: Render,
+{
+    fn render_into<W: Write>(self, writer: &mut W) -> Result {
+        let mut render_item = self.render_item;
+        for item in self.items {
+            render_item(item).render_into(writer)?;
+        }
+        Ok(())
+    }
+}
+
+/// Renders a clone, which is cheap for ranges, iterators over borrowed
+/// items and closures that only capture references
+impl<I, F, R> RenderRef for Each<I, F>
+where
+    I: IntoIterator + Clone,
+    F: FnMut(I::Item) -> R + Clone,
+    R: Render,
+{
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        self.clone().render_into(writer)
+    }
+}
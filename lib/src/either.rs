@@ -0,0 +1,67 @@
+//! The either component
+
+use crate::{Render, RenderRef};
+use std::fmt::{Result, Write};
+
+/// One of two renderable values
+///
+/// This is what `if` / `else` children of `rsx!` expand into, so that
+/// each branch can produce a different component type without boxing.
+/// `match` children nest `Either`s, one level per arm, to form an n-ary
+/// sum of their arm types.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen_macros::html;
+/// let logged_in = false;
+///
+/// let result = html! {
+///     <nav>
+///         if logged_in {
+///             <a href={"/logout"}>{"Log out"}</a>
+///         } else {
+///             <button>{"Log in"}</button>
+///         }
+///     </nav>
+/// }.unwrap();
+///
+/// assert_eq!(result, "<nav><button>Log in</button></nav>");
+/// ```
+#[derive(Debug, Clone)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+/// Renders `L` or `R`
+impl<L: Render, R: Render> Render for Either<L, R> {
+    fn render_into<W: Write>(self, writer: &mut W) -> Result {
+        match self {
+            Either::Left(left) => left.render_into(writer),
+            Either::Right(right) => right.render_into(writer),
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            Either::Left(left) => left.size_hint(),
+            Either::Right(right) => right.size_hint(),
+        }
+    }
+}
+
+impl<L: RenderRef, R: RenderRef> RenderRef for Either<L, R> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        match self {
+            Either::Left(left) => left.render_ref(writer),
+            Either::Right(right) => right.render_ref(writer),
+        }
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        match self {
+            Either::Left(left) => left.size_hint_ref(),
+            Either::Right(right) => right.size_hint_ref(),
+        }
+    }
+}
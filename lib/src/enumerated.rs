@@ -0,0 +1,322 @@
+//! Enums of the keywords accepted by enumerated attributes
+//!
+//! Attributes like `type`, `target` or `method` only accept some keywords,
+//! and these enums name them so a typo is a compile error instead of an
+//! ignored attribute:
+//!
+//! ```rust
+//! # use pretty_assertions::assert_eq;
+//! use html_codegen::html;
+//! use html_codegen::enumerated::{InputType, Loading, Rel, Target};
+//!
+//! let result = html! {
+//!     <>
+//!         <input type={InputType::Email} />
+//!         <a href="/docs" target={Target::Blank} rel={[Rel::Noopener, Rel::Noreferrer]} />
+//!         <img src="/logo.png" loading={Loading::Lazy} />
+//!     </>
+//! }.unwrap();
+//!
+//! assert_eq!(
+//!     result,
+//!     concat!(
+//!         r#"<input type="email"/>"#,
+//!         r#"<a href="/docs" target="_blank" rel="noopener noreferrer"></a>"#,
+//!         r#"<img src="/logo.png" loading="lazy"/>"#,
+//!     )
+//! );
+//! ```
+//!
+//! With the `strict-html` feature, `rsx!` requires these enums for the
+//! attributes they describe, or literals that are one of their keywords.
+//! Other values, like the name of a browsing context in `target`, can be
+//! passed through [`Unchecked`].
+
+use crate::{ToAttribute, AV};
+use std::borrow::Cow;
+use std::fmt;
+
+/// An enum of the keywords of an attribute
+pub trait Enumerated: Copy {
+    /// The keyword, as rendered
+    fn as_str(self) -> &'static str;
+}
+
+/// An enumerated attribute that accepts a space separated list of keywords,
+/// passed as an array or a `Vec`
+pub trait TokenList: Enumerated {}
+
+macro_rules! enumerated {
+    ($(#[$meta:meta])* $name:ident { $($(#[$variant_meta:meta])* $variant:ident => $keyword:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* #[doc = concat!("`", $keyword, "`")] $variant,)*
+        }
+
+        impl Enumerated for $name {
+            fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $keyword,)*
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl<'a> ToAttribute<'a> for $name {
+            fn from_value(self) -> AV<'a> {
+                AV::Some(Cow::Borrowed(self.as_str()))
+            }
+        }
+    };
+}
+
+enumerated! {
+    /// The `type` of an `<input>`
+    InputType {
+        Button => "button",
+        Checkbox => "checkbox",
+        Color => "color",
+        Date => "date",
+        DatetimeLocal => "datetime-local",
+        Email => "email",
+        File => "file",
+        Hidden => "hidden",
+        Image => "image",
+        Month => "month",
+        Number => "number",
+        Password => "password",
+        Radio => "radio",
+        Range => "range",
+        Reset => "reset",
+        Search => "search",
+        Submit => "submit",
+        Tel => "tel",
+        Text => "text",
+        Time => "time",
+        Url => "url",
+        Week => "week",
+    }
+}
+
+enumerated! {
+    /// The `type` of a `<button>`
+    ButtonType {
+        Submit => "submit",
+        Reset => "reset",
+        Button => "button",
+    }
+}
+
+enumerated! {
+    /// The browsing context of a `target` or `formtarget`
+    Target {
+        Blank => "_blank",
+        Self_ => "_self",
+        Parent => "_parent",
+        Top => "_top",
+    }
+}
+
+enumerated! {
+    /// The link types of a `rel`
+    Rel {
+        Alternate => "alternate",
+        Author => "author",
+        Bookmark => "bookmark",
+        Canonical => "canonical",
+        DnsPrefetch => "dns-prefetch",
+        External => "external",
+        Help => "help",
+        Icon => "icon",
+        License => "license",
+        Manifest => "manifest",
+        Me => "me",
+        Modulepreload => "modulepreload",
+        Next => "next",
+        Nofollow => "nofollow",
+        Noopener => "noopener",
+        Noreferrer => "noreferrer",
+        Opener => "opener",
+        Pingback => "pingback",
+        Preconnect => "preconnect",
+        Prefetch => "prefetch",
+        Preload => "preload",
+        Prev => "prev",
+        PrivacyPolicy => "privacy-policy",
+        Search => "search",
+        Stylesheet => "stylesheet",
+        Tag => "tag",
+        TermsOfService => "terms-of-service",
+    }
+}
+
+enumerated! {
+    /// When an `<img>` or `<iframe>` is loaded
+    Loading {
+        Eager => "eager",
+        Lazy => "lazy",
+    }
+}
+
+enumerated! {
+    /// The `method` of a `<form>`, or the `formmethod` of its buttons
+    Method {
+        Get => "get",
+        Post => "post",
+        Dialog => "dialog",
+    }
+}
+
+enumerated! {
+    /// The autofill tokens of an `autocomplete`
+    Autocomplete {
+        On => "on",
+        Off => "off",
+        Shipping => "shipping",
+        Billing => "billing",
+        Home => "home",
+        Work => "work",
+        Mobile => "mobile",
+        Fax => "fax",
+        Pager => "pager",
+        Name => "name",
+        HonorificPrefix => "honorific-prefix",
+        GivenName => "given-name",
+        AdditionalName => "additional-name",
+        FamilyName => "family-name",
+        HonorificSuffix => "honorific-suffix",
+        Nickname => "nickname",
+        Username => "username",
+        NewPassword => "new-password",
+        CurrentPassword => "current-password",
+        OneTimeCode => "one-time-code",
+        OrganizationTitle => "organization-title",
+        Organization => "organization",
+        StreetAddress => "street-address",
+        AddressLine1 => "address-line1",
+        AddressLine2 => "address-line2",
+        AddressLine3 => "address-line3",
+        AddressLevel4 => "address-level4",
+        AddressLevel3 => "address-level3",
+        AddressLevel2 => "address-level2",
+        AddressLevel1 => "address-level1",
+        Country => "country",
+        CountryName => "country-name",
+        PostalCode => "postal-code",
+        CcName => "cc-name",
+        CcGivenName => "cc-given-name",
+        CcAdditionalName => "cc-additional-name",
+        CcFamilyName => "cc-family-name",
+        CcNumber => "cc-number",
+        CcExp => "cc-exp",
+        CcExpMonth => "cc-exp-month",
+        CcExpYear => "cc-exp-year",
+        CcCsc => "cc-csc",
+        CcType => "cc-type",
+        TransactionCurrency => "transaction-currency",
+        TransactionAmount => "transaction-amount",
+        Language => "language",
+        Bday => "bday",
+        BdayDay => "bday-day",
+        BdayMonth => "bday-month",
+        BdayYear => "bday-year",
+        Sex => "sex",
+        Url => "url",
+        Photo => "photo",
+        Tel => "tel",
+        TelCountryCode => "tel-country-code",
+        TelNational => "tel-national",
+        TelAreaCode => "tel-area-code",
+        TelLocal => "tel-local",
+        TelExtension => "tel-extension",
+        Email => "email",
+        Impp => "impp",
+        Webauthn => "webauthn",
+    }
+}
+
+impl TokenList for Rel {}
+impl TokenList for Autocomplete {}
+
+/// Renders the keywords separated by spaces, or omits the attribute when empty
+impl<'a, E: TokenList> ToAttribute<'a> for Vec<E> {
+    fn from_value(self) -> AV<'a> {
+        if self.is_empty() {
+            return AV::None;
+        }
+
+        let keywords: Vec<_> = self.into_iter().map(Enumerated::as_str).collect();
+        AV::Some(Cow::Owned(keywords.join(" ")))
+    }
+}
+
+/// Renders the keywords separated by spaces, or omits the attribute when empty
+impl<'a, E: TokenList, const N: usize> ToAttribute<'a> for [E; N] {
+    fn from_value(self) -> AV<'a> {
+        Vec::from(self).from_value()
+    }
+}
+
+impl<'a, E: Enumerated> ToAttribute<'a> for Option<E> {
+    fn from_value(self) -> AV<'a> {
+        match self {
+            None => AV::None,
+            Some(keyword) => AV::Some(Cow::Borrowed(keyword.as_str())),
+        }
+    }
+}
+
+/// Any attribute value, allowed for an enumerated attribute under the
+/// `strict-html` feature
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// use html_codegen::html;
+/// use html_codegen::enumerated::Unchecked;
+///
+/// let frame = "preview";
+/// let result = html! { <a href="/draft" target={Unchecked(frame)} /> }.unwrap();
+///
+/// assert_eq!(result, r#"<a href="/draft" target="preview"></a>"#);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Unchecked<T>(pub T);
+
+impl<'a, T: ToAttribute<'a>> ToAttribute<'a> for Unchecked<T> {
+    fn from_value(self) -> AV<'a> {
+        self.0.from_value()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Unchecked<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// A value that the `strict-html` feature accepts for an attribute whose
+/// keywords are `E`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a value of `{E}`",
+    label = "expected a value of `{E}`",
+    note = "values that aren't one of the keywords can be wrapped in `html_codegen::enumerated::Unchecked`"
+)]
+pub trait ValueOf<E> {}
+
+impl<E: Enumerated> ValueOf<E> for E {}
+impl<E: Enumerated> ValueOf<E> for Option<E> {}
+impl<E: TokenList> ValueOf<E> for Vec<E> {}
+impl<E: TokenList, const N: usize> ValueOf<E> for [E; N] {}
+impl<E, T> ValueOf<E> for Unchecked<T> {}
+
+/// Used by `rsx!` to require a value of `E`, under the `strict-html` feature
+#[doc(hidden)]
+pub fn expect_value<E, V: ValueOf<E>>(value: V) -> V {
+    value
+}
@@ -1,6 +1,6 @@
 //! The fragment component
 
-use crate::Render;
+use crate::{Render, RenderRef};
 use std::fmt::{Result, Write};
 
 /// A top-level root component to combine a same-level components
@@ -26,4 +26,18 @@ impl<T: Render> Render for Fragment<T> {
     fn render_into<W: Write>(self, writer: &mut W) -> Result {
         self.children.render_into(writer)
     }
+
+    fn size_hint(&self) -> usize {
+        self.children.size_hint()
+    }
+}
+
+impl<T: RenderRef + Render> RenderRef for Fragment<T> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        self.children.render_ref(writer)
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.children.size_hint_ref()
+    }
 }
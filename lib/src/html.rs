@@ -1,6 +1,6 @@
 //! HTML utilities
 
-use crate::Render;
+use crate::{Render, RenderRef};
 use std::fmt::{Result, Write};
 
 /// HTML 5 doctype declaration
@@ -25,6 +25,20 @@ pub struct HTML5Doctype;
 
 impl Render for HTML5Doctype {
     fn render_into<W: Write>(self, writer: &mut W) -> Result {
+        self.render_ref(writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.size_hint_ref()
+    }
+}
+
+impl RenderRef for HTML5Doctype {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
         write!(writer, "<!DOCTYPE html>")
     }
+
+    fn size_hint_ref(&self) -> usize {
+        "<!DOCTYPE html>".len()
+    }
 }
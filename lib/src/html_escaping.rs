@@ -1,4 +1,5 @@
-use std::fmt::{Result, Write};
+use crate::raw_text::RawTextWriter;
+use std::fmt::{Display, Formatter, Result, Write};
 
 /// Simple HTML escaping, so strings can be safely rendered.
 ///
@@ -24,3 +25,131 @@ pub fn escape_html<W: Write>(html: &str, writer: &mut W) -> Result {
 
     Ok(())
 }
+
+/// XML escaping of text, used for text rendered under
+/// [`Xml`](crate::xml::Xml).
+///
+/// Only XML's predefined entities are used. A carriage return is written as
+/// a character reference, so XML parsers don't turn it into a line feed,
+/// and characters that XML documents can't contain are replaced with
+/// U+FFFD.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen::html_escaping;
+///
+/// let mut buf = String::new();
+/// html_escaping::escape_xml("Tom & Jerry's\r\n\u{1b}", &mut buf).unwrap();
+/// assert_eq!(buf, "Tom &amp; Jerry&apos;s&#13;\n\u{fffd}");
+/// ```
+pub fn escape_xml<W: Write>(text: &str, writer: &mut W) -> Result {
+    for c in text.chars() {
+        match c {
+            '\r' => write!(writer, "&#13;")?,
+            c => write_xml_char(c, writer)?,
+        };
+    }
+
+    Ok(())
+}
+
+/// XML escaping of attribute values, used for attributes rendered under
+/// [`Xml`](crate::xml::Xml).
+///
+/// Like [`escape_xml`], and tabs and line feeds are written as character
+/// references too, since XML parsers turn them into spaces in attribute
+/// values.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen::html_escaping;
+///
+/// let mut buf = String::new();
+/// html_escaping::escape_xml_attribute("a\tb\nc", &mut buf).unwrap();
+/// assert_eq!(buf, "a&#9;b&#10;c");
+/// ```
+pub fn escape_xml_attribute<W: Write>(value: &str, writer: &mut W) -> Result {
+    for c in value.chars() {
+        match c {
+            '\t' | '\n' | '\r' => write!(writer, "&#{};", c as u32)?,
+            c => write_xml_char(c, writer)?,
+        };
+    }
+
+    Ok(())
+}
+
+fn write_xml_char<W: Write>(c: char, writer: &mut W) -> Result {
+    match c {
+        '>' => write!(writer, "&gt;"),
+        '<' => write!(writer, "&lt;"),
+        '"' => write!(writer, "&quot;"),
+        '&' => write!(writer, "&amp;"),
+        '\'' => write!(writer, "&apos;"),
+        '\t' | '\n' | '\r' => writer.write_char(c),
+        '\u{0}'..='\u{1f}' | '\u{fffe}' | '\u{ffff}' => writer.write_char('\u{fffd}'),
+        c => writer.write_char(c),
+    }
+}
+
+/// Escapes a CSS declaration value, so it can't end its declaration or rule
+/// and inject new ones.
+///
+/// The characters that could do so are written as CSS hex escapes, which
+/// browsers read back as the literal character inside the value. That
+/// includes quotes, which would start a string running over the following
+/// declarations, and the `/` of a `/*` comment. A value can't hold a CSS
+/// string as a result.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen::html_escaping;
+///
+/// let mut buf = String::new();
+/// html_escaping::escape_css_value("red; background: url(evil)", &mut buf).unwrap();
+/// assert_eq!(buf, r"red\3b  background: url(evil)");
+/// ```
+pub fn escape_css_value<W: Write>(value: &str, writer: &mut W) -> Result {
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ';' | '{' | '}' | '\\' | '"' | '\'' => write!(writer, "\\{:x} ", c as u32)?,
+            '/' if chars.peek() == Some(&'*') => write!(writer, "\\{:x} ", c as u32)?,
+            c if c.is_control() => write!(writer, "\\{:x} ", c as u32)?,
+            c => writer.write_char(c)?,
+        };
+    }
+
+    Ok(())
+}
+
+/// Displays the contents of the raw text element `tag_name`, like
+/// `<script>`, so they can't close it early.
+///
+/// Used by `html_format!` for the values in `<script>` and `<style>`.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen::html_escaping::RawText;
+///
+/// let script = RawText::new("script", "document.write('</script>')");
+/// assert_eq!(script.to_string(), r"document.write('<\/script>')");
+/// ```
+pub struct RawText<'a, T: Display> {
+    tag_name: &'a str,
+    contents: T,
+}
+
+impl<'a, T: Display> RawText<'a, T> {
+    pub fn new(tag_name: &'a str, contents: T) -> Self {
+        Self { tag_name, contents }
+    }
+}
+
+impl<T: Display> Display for RawText<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut writer = RawTextWriter::new(self.tag_name, f);
+        write!(writer, "{}", self.contents)?;
+        writer.finish()
+    }
+}
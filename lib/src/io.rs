@@ -0,0 +1,52 @@
+//! Rendering into [`std::io::Write`] sinks, like files, sockets or a
+//! `Vec<u8>`, without rendering into a `String` first
+
+use std::fmt;
+use std::io;
+
+/// Adapts an [`io::Write`] into the [`fmt::Write`] that
+/// [`Render::render_into`](crate::Render::render_into) writes to, keeping
+/// the [`io::Error`] that stopped it
+///
+/// Every write goes to the inner writer, so files and sockets are best
+/// wrapped in an [`io::BufWriter`].
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// use html_codegen::io::IoWriter;
+/// use html_codegen::{rsx, Render};
+///
+/// let mut writer = IoWriter::new(Vec::new());
+/// rsx! { <p>{"Hello"}</p> }.render_into(&mut writer).unwrap();
+///
+/// assert_eq!(writer.into_inner(), b"<p>Hello</p>");
+/// ```
+#[derive(Debug)]
+pub struct IoWriter<W: io::Write> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W: io::Write> IoWriter<W> {
+    pub fn new(inner: W) -> Self {
+        Self { inner, error: None }
+    }
+
+    /// The error of the inner writer that stopped the render, if any
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, text: &str) -> fmt::Result {
+        self.inner.write_all(text.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
@@ -76,6 +76,26 @@
 //! assert_eq!(tree, "<div><p>&lt;Hello /&gt;</p><p><Hello /></p></div>");
 //! ```
 //!
+//! The contents of `<script>` and `<style>` aren't HTML, so strings in them are rendered without
+//! escaping. Instead, any `</script` or `</style` in them is rewritten to `<\/script` or
+//! `<\/style`, so they can't close the element early:
+//!
+//! ```rust
+//! # use pretty_assertions::assert_eq;
+//!
+//! use html_codegen::html;
+//!
+//! let message = "</script><script>alert(1)";
+//! let tree = html! {
+//!   <script>"if (a && b) { console.log(\"" {message} "\"); }"</script>
+//! }.unwrap();
+//!
+//! assert_eq!(
+//!   tree,
+//!   r#"<script>if (a && b) { console.log("<\/script><script>alert(1)"); }</script>"#
+//! );
+//! ```
+//!
 //! ## Custom components
 //!
 //! Render's greatest ability is to provide type-safety along with custom renderable components.
@@ -104,9 +124,10 @@
 //! If you pay close attention, you see that the function `Heading` is:
 //!
 //! * declared with an uppercase. Underneath, it generates a struct with the same name, and
-//! implements the `Render` trait on it.
+//!   implements the `Render` trait on it.
 //! * does not have a return type. This is because everything is written to a writer, for
-//! performance reasons.
+//!   performance reasons. Components that can fail return a `Result` instead, see
+//!   [`RenderError`].
 //!
 //! ### Full example
 //!
@@ -142,7 +163,7 @@
 //!
 //! // This can be a route in Rocket, the web framework,
 //! // for instance.
-//! pub fn some_page(user_name: &str) -> Result<String, std::fmt::Error> {
+//! pub fn some_page(user_name: &str) -> Result<String, html_codegen::RenderError> {
 //!     html! {
 //!       <Page title={"Home"}>
 //!         {format!("Welcome, {user_name}")}
@@ -164,18 +185,46 @@
 //! # assert_eq!(actual, expected);
 //! ```
 
+// Lets the macros refer to `::html_codegen` from within this crate
+extern crate self as html_codegen;
+
+pub mod class_list;
+pub mod comment;
+pub mod context;
+pub mod each;
+pub mod either;
+pub mod enumerated;
 pub mod fragment;
 pub mod html;
 pub mod html_escaping;
+pub mod io;
+mod minify;
 mod numbers;
+mod pretty;
+pub mod props;
+mod raw_text;
 mod render;
+mod render_error;
 mod simple_element;
+#[cfg(feature = "tokio")]
+pub mod stream;
+pub mod style;
 mod text_element;
+pub mod xml;
 
-pub use self::render::Render;
+pub use self::render::{Render, RenderRef};
+pub use self::render_error::RenderError;
+pub use class_list::{ClassList, ToClasses};
+pub use comment::Comment;
+pub use context::{use_context, Provide};
+pub use each::Each;
+pub use either::Either;
 pub use fragment::Fragment;
-pub use html_codegen_macros::{component, html, html_format, html_format_args, rsx};
+pub use html_codegen_macros::{
+    component, html, html_format, html_format_args, html_write, rsx, Props,
+};
 pub use ordered_hash_map::OrderedHashMap;
 pub use simple_element::SimpleElement;
 pub use simple_element::{ToAttribute, AV};
+pub use style::{Style, ToStyle, ToStyleValue};
 pub use text_element::Raw;
@@ -0,0 +1,194 @@
+//! Minified rendering, for production responses
+//!
+//! The output is rewritten as it's written, which keeps the page looking the same:
+//!
+//! * attribute values that don't need quotes lose them, and void elements
+//!   lose the `/` of `<br/>`
+//! * end tags that HTML lets a page leave out, like `</li>`, `</p>` or
+//!   `</td>`, are left out when what follows them closes the element anyway
+//! * whitespace between two tags, with no other text, is collapsed to a
+//!   single space, except in `<pre>` and `<textarea>`
+//!
+//! Text with anything but whitespace in it is written as it is, as CSS like
+//! `white-space: pre-wrap` can show its whitespace.
+//!
+//! Elements rendered under [`Xml`](crate::xml::Xml) are left as they are.
+
+use crate::raw_text::in_raw_text;
+use crate::xml::in_xml;
+use crate::{Render, RenderError};
+use html_codegen_syntax::{
+    has_optional_end_tag, is_preformatted_element, may_omit_before, may_omit_before_parent_end,
+    write_start_tag, Tag,
+};
+use std::fmt::{Result, Write};
+
+/// Renders `renderable`, minified
+pub(crate) fn render_minified<T: Render>(
+    renderable: T,
+) -> std::result::Result<String, RenderError> {
+    let mut output = String::new();
+    let mut writer = MinifyWriter::new(&mut output);
+    renderable.try_render_into(&mut writer)?;
+    writer.finish()?;
+    Ok(output)
+}
+
+/// Writes HTML through, minified
+pub(crate) struct MinifyWriter<'a, W: Write> {
+    writer: &'a mut W,
+    /// The tag being written, from its `<`
+    tag: String,
+    /// The quote of the attribute value being written in `tag`, if any
+    quote: Option<char>,
+    /// The name of an end tag that was left out, until what follows it
+    /// shows whether it can be
+    omitted: Option<String>,
+    /// The number of `<pre>` and `<textarea>` the text is in
+    preformatted: usize,
+    /// The whitespace written since the last tag, while there's nothing else
+    whitespace: Option<String>,
+}
+
+impl<'a, W: Write> MinifyWriter<'a, W> {
+    pub(crate) fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            tag: String::new(),
+            quote: None,
+            omitted: None,
+            preformatted: 0,
+            whitespace: Some(String::new()),
+        }
+    }
+
+    /// Writes what's held back, once there's nothing left to write. An end
+    /// tag at the end is kept, as the output may be part of a page.
+    pub(crate) fn finish(mut self) -> Result {
+        self.collapse_whitespace()?;
+        self.keep_omitted()?;
+        self.writer.write_str(&std::mem::take(&mut self.tag))
+    }
+
+    fn write_minified_char(&mut self, c: char) -> Result {
+        if !self.tag.is_empty() {
+            let after_equals = self.tag.ends_with('=');
+            self.tag.push(c);
+
+            match (self.quote, c) {
+                (Some(quote), c) if c == quote => self.quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') if after_equals && !self.tag.starts_with("<!") => {
+                    self.quote = Some(c)
+                }
+                (None, '>') => {
+                    let tag = std::mem::take(&mut self.tag);
+                    self.write_tag(&tag)?;
+                }
+                _ => {}
+            }
+
+            return Ok(());
+        }
+
+        if c == '<' {
+            self.collapse_whitespace()?;
+            self.tag.push(c);
+            return Ok(());
+        }
+
+        if let Some(whitespace) = &mut self.whitespace {
+            if self.preformatted == 0 && c.is_ascii_whitespace() {
+                whitespace.push(c);
+                return Ok(());
+            }
+        }
+
+        self.keep_whitespace()?;
+        self.writer.write_char(c)
+    }
+
+    fn write_tag(&mut self, tag: &str) -> Result {
+        self.whitespace = Some(String::new());
+
+        match Tag::parse(tag) {
+            Tag::End(name) => {
+                self.resolve_omitted(|omitted| may_omit_before_parent_end(omitted, name))?;
+
+                if is_preformatted_element(name) {
+                    self.preformatted = self.preformatted.saturating_sub(1);
+                }
+
+                if has_optional_end_tag(name) {
+                    self.omitted = Some(name.to_string());
+                    return Ok(());
+                }
+
+                self.writer.write_str(tag)
+            }
+
+            Tag::Start(name) => {
+                self.resolve_omitted(|omitted| may_omit_before(omitted, name))?;
+
+                if is_preformatted_element(name) {
+                    self.preformatted += 1;
+                }
+
+                write_start_tag(tag, name, self.writer)
+            }
+
+            Tag::Other => {
+                self.keep_omitted()?;
+                self.writer.write_str(tag)
+            }
+        }
+    }
+
+    /// Writes the omitted end tag, unless `may_omit` says it can be left out
+    fn resolve_omitted(&mut self, may_omit: impl FnOnce(&str) -> bool) -> Result {
+        match self.omitted.take() {
+            Some(omitted) if !may_omit(&omitted) => write!(self.writer, "</{omitted}>"),
+            _ => Ok(()),
+        }
+    }
+
+    fn keep_omitted(&mut self) -> Result {
+        self.resolve_omitted(|_| false)
+    }
+
+    /// Writes the whitespace held back as it is, as it's part of the text
+    /// that follows it
+    fn keep_whitespace(&mut self) -> Result {
+        self.keep_omitted()?;
+        match self.whitespace.take() {
+            Some(whitespace) => self.writer.write_str(&whitespace),
+            None => Ok(()),
+        }
+    }
+
+    /// Writes the whitespace held back between two tags as a single space
+    fn collapse_whitespace(&mut self) -> Result {
+        match self.whitespace.take() {
+            Some(whitespace) if !whitespace.is_empty() => {
+                self.keep_omitted()?;
+                self.writer.write_char(' ')
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<W: Write> Write for MinifyWriter<'_, W> {
+    fn write_str(&mut self, text: &str) -> Result {
+        if self.tag.is_empty() && (in_raw_text() || in_xml()) {
+            self.keep_whitespace()?;
+            return self.writer.write_str(text);
+        }
+
+        for c in text.chars() {
+            self.write_minified_char(c)?;
+        }
+
+        Ok(())
+    }
+}
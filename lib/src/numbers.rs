@@ -1,29 +1,80 @@
 //! Render impls for numeric primitives
 
-use crate::Render;
+use crate::{Render, RenderRef};
 use std::fmt::{Result, Write};
 
 macro_rules! simple_render_impl {
     ($t:ty) => {
+        simple_render_impl!($t, |_| 1);
+    };
+    ($t:ty, $size_hint:expr) => {
         impl Render for $t {
             fn render_into<W: Write>(self, writer: &mut W) -> Result {
+                self.render_ref(writer)
+            }
+
+            fn size_hint(&self) -> usize {
+                self.size_hint_ref()
+            }
+        }
+
+        impl RenderRef for $t {
+            fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
                 write!(writer, "{self}")
             }
+
+            fn size_hint_ref(&self) -> usize {
+                let size_hint: fn($t) -> usize = $size_hint;
+                size_hint(*self)
+            }
+        }
+    };
+}
+
+/// The number of digits of an unsigned integer
+macro_rules! digits {
+    () => {
+        |n| n.checked_ilog10().map_or(1, |log| log as usize + 1)
+    };
+}
+
+/// The number of digits of a signed integer, and its sign
+macro_rules! signed_digits {
+    () => {
+        |n| {
+            usize::from(n < 0)
+                + n.unsigned_abs()
+                    .checked_ilog10()
+                    .map_or(1, |log| log as usize + 1)
         }
     };
 }
 
 simple_render_impl!(f32);
 simple_render_impl!(f64);
-simple_render_impl!(i128);
-simple_render_impl!(i16);
-simple_render_impl!(i32);
-simple_render_impl!(i64);
-simple_render_impl!(i8);
-simple_render_impl!(isize);
-simple_render_impl!(u128);
-simple_render_impl!(u16);
-simple_render_impl!(u32);
-simple_render_impl!(u64);
-simple_render_impl!(u8);
-simple_render_impl!(usize);
+simple_render_impl!(i128, signed_digits!());
+simple_render_impl!(i16, signed_digits!());
+simple_render_impl!(i32, signed_digits!());
+simple_render_impl!(i64, signed_digits!());
+simple_render_impl!(i8, signed_digits!());
+simple_render_impl!(isize, signed_digits!());
+simple_render_impl!(u128, digits!());
+simple_render_impl!(u16, digits!());
+simple_render_impl!(u32, digits!());
+simple_render_impl!(u64, digits!());
+simple_render_impl!(u8, digits!());
+simple_render_impl!(usize, digits!());
+
+#[cfg(test)]
+mod tests {
+    use crate::Render;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn size_hints_are_the_lengths_of_integers() {
+        for n in [0, 7, 10, 99, 100, -1, -10, i64::MAX, i64::MIN] {
+            assert_eq!(n.size_hint(), n.to_string().len(), "{n}");
+        }
+        assert_eq!(u128::MAX.size_hint(), u128::MAX.to_string().len());
+    }
+}
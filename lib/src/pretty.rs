@@ -0,0 +1,191 @@
+//! Pretty-printed rendering, for debugging and snapshots
+//!
+//! Block-level elements are put on their own lines and indented by their
+//! depth, while inline elements and text stay as they are. Whitespace
+//! around block-level elements doesn't change how a page looks, so the
+//! output is the same page as the compact one. The contents of `<pre>`,
+//! `<textarea>`, `<script>` and `<style>` are whitespace-sensitive, and are
+//! rendered as-is.
+
+use crate::{Render, RenderError};
+use std::cell::RefCell;
+use std::fmt::{Result, Write};
+
+thread_local! {
+    /// The layout of the pretty render in progress, if any
+    static PRETTY: RefCell<Option<Pretty>> = const { RefCell::new(None) };
+}
+
+struct Pretty {
+    indent: String,
+    /// The number of block-level elements around the one being rendered
+    depth: usize,
+    /// Whether whitespace is significant where the render is
+    preformatted: bool,
+    /// Whether a block-level element was rendered in the current element,
+    /// so its closing tag goes on its own line
+    wrote_block: bool,
+}
+
+/// The elements that whitespace around doesn't change the page
+const BLOCK_ELEMENTS: &[&str] = &[
+    "html",
+    "head",
+    "body",
+    "title",
+    "base",
+    "link",
+    "meta",
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "details",
+    "dialog",
+    "dd",
+    "div",
+    "dl",
+    "dt",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hgroup",
+    "hr",
+    "li",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "summary",
+    "table",
+    "caption",
+    "colgroup",
+    "col",
+    "thead",
+    "tbody",
+    "tfoot",
+    "tr",
+    "td",
+    "th",
+    "ul",
+];
+
+/// The elements whose contents are whitespace-sensitive
+const PREFORMATTED_ELEMENTS: &[&str] = &["pre", "textarea", "script", "style"];
+
+/// Renders `renderable` with block-level elements indented by `indent`
+pub(crate) fn render_pretty<T: Render>(
+    renderable: T,
+    indent: &str,
+) -> std::result::Result<String, RenderError> {
+    let pretty = Pretty {
+        indent: indent.to_string(),
+        depth: 0,
+        preformatted: false,
+        wrote_block: false,
+    };
+    let outer = PRETTY.with(|state| state.replace(Some(pretty)));
+    let _restore = Restore(outer);
+
+    let mut output = String::new();
+    renderable.try_render_into(&mut output)?;
+
+    match output.strip_prefix('\n') {
+        Some(output) => Ok(output.to_string()),
+        None => Ok(output),
+    }
+}
+
+/// Restores the layout of an enclosing pretty render, even if rendering
+/// panics
+struct Restore(Option<Pretty>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        PRETTY.with(|state| *state.borrow_mut() = self.0.take());
+    }
+}
+
+/// Renders an element, laid out for the pretty render in progress if any
+pub(crate) fn render_element<W: Write>(
+    tag_name: &str,
+    writer: &mut W,
+    open: impl FnOnce(&mut W) -> Result,
+    contents: impl FnOnce(&mut W) -> Result,
+    close: impl FnOnce(&mut W) -> Result,
+) -> Result {
+    let outer = PRETTY.with(|state| {
+        state
+            .borrow()
+            .as_ref()
+            .map(|pretty| (pretty.depth, pretty.preformatted, pretty.wrote_block))
+    });
+
+    let Some((depth, preformatted, wrote_block)) = outer else {
+        open(writer)?;
+        contents(writer)?;
+        return close(writer);
+    };
+
+    let block = !preformatted && BLOCK_ELEMENTS.contains(&tag_name);
+    if block {
+        write_line_break(writer, depth)?;
+    }
+    open(writer)?;
+
+    update(|pretty| {
+        pretty.depth = depth + usize::from(block);
+        pretty.preformatted = preformatted || PREFORMATTED_ELEMENTS.contains(&tag_name);
+        pretty.wrote_block = false;
+    });
+    contents(writer)?;
+
+    let contains_block = PRETTY.with(|state| {
+        state
+            .borrow()
+            .as_ref()
+            .is_some_and(|pretty| pretty.wrote_block)
+    });
+    if block && contains_block {
+        write_line_break(writer, depth)?;
+    }
+    close(writer)?;
+
+    update(|pretty| {
+        pretty.depth = depth;
+        pretty.preformatted = preformatted;
+        pretty.wrote_block = wrote_block || block;
+    });
+    Ok(())
+}
+
+fn update(change: impl FnOnce(&mut Pretty)) {
+    PRETTY.with(|state| {
+        if let Some(pretty) = state.borrow_mut().as_mut() {
+            change(pretty);
+        }
+    });
+}
+
+fn write_line_break<W: Write>(writer: &mut W, depth: usize) -> Result {
+    let indent = PRETTY.with(|state| {
+        state
+            .borrow()
+            .as_ref()
+            .map(|pretty| pretty.indent.repeat(depth))
+    });
+
+    writer.write_char('\n')?;
+    writer.write_str(&indent.unwrap_or_default())
+}
@@ -0,0 +1,240 @@
+//! Building blocks of the props builders generated by `#[component]` and
+//! `#[derive(Props)]`
+//!
+//! `rsx!` creates custom components through their builder, which is what
+//! allows optional props to be omitted:
+//!
+//! ```rust
+//! # use pretty_assertions::assert_eq;
+//! use html_codegen::enumerated::ButtonType;
+//! use html_codegen::{component, html, rsx, Render};
+//!
+//! #[component]
+//! fn Button<'a>(
+//!     label: &'a str,
+//!     #[prop(default = ButtonType::Button)] kind: ButtonType,
+//!     title: Option<&'a str>,
+//! ) {
+//!     rsx! { <button type={kind} title={title}>{label}</button> }
+//! }
+//!
+//! let rendered = html! { <Button label="Send" /> }.unwrap();
+//! let built = Button::builder().label("Send").kind(ButtonType::Submit).build().render().unwrap();
+//!
+//! assert_eq!(rendered, r#"<button type="button">Send</button>"#);
+//! assert_eq!(built, r#"<button type="submit">Send</button>"#);
+//! ```
+//!
+//! Each prop of a builder is either [`Unset`] or [`Set`], and `build` is only
+//! available once every required prop is [`Set`].
+//!
+//! Components that aren't [`Props`], like structs that implement
+//! [`Render`](crate::Render) by hand, are created with a struct literal
+//! instead, so all of their fields have to be given:
+//!
+//! ```rust
+//! # use pretty_assertions::assert_eq;
+//! use html_codegen::{html, rsx, Render};
+//!
+//! struct Badge<'a> {
+//!     count: u32,
+//!     label: &'a str,
+//! }
+//!
+//! impl Render for Badge<'_> {
+//!     fn render_into<W: std::fmt::Write>(self, writer: &mut W) -> std::fmt::Result {
+//!         Render::render_into(rsx! { <span title={self.label}>{self.count}</span> }, writer)
+//!     }
+//! }
+//!
+//! let rendered = html! { <Badge count={3} label="Unread" /> }.unwrap();
+//!
+//! assert_eq!(rendered, r#"<span title="Unread">3</span>"#);
+//! ```
+
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+/// A prop that wasn't given to the builder
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unset;
+
+/// A prop that was given to the builder
+#[derive(Debug, Clone, Copy)]
+pub struct Set<T>(pub T);
+
+/// The state of a prop that has to be set before building
+#[diagnostic::on_unimplemented(
+    message = "missing a required prop of type `{T}`",
+    label = "a required prop isn't given",
+    note = "props can be made optional with `#[prop(default)]` or an `Option` type"
+)]
+pub trait Required<T> {
+    fn into_value(self) -> T;
+}
+
+impl<T> Required<T> for Set<T> {
+    fn into_value(self) -> T {
+        self.0
+    }
+}
+
+/// The state of a prop that falls back to a default value
+pub trait Optional<T> {
+    fn value_or_else(self, default: impl FnOnce() -> T) -> T;
+}
+
+impl<T> Optional<T> for Set<T> {
+    fn value_or_else(self, _default: impl FnOnce() -> T) -> T {
+        self.0
+    }
+}
+
+impl<T> Optional<T> for Unset {
+    fn value_or_else(self, default: impl FnOnce() -> T) -> T {
+        default()
+    }
+}
+
+/// A prop that a component rendered by reference can clone
+///
+/// `#[component]` implements [`RenderRef`](crate::RenderRef) when every prop
+/// is `CloneProp`, which is every [`Clone`] type.
+pub trait CloneProp {
+    fn clone_prop(&self) -> Self;
+}
+
+impl<T: Clone> CloneProp for T {
+    fn clone_prop(&self) -> Self {
+        self.clone()
+    }
+}
+
+/// Implemented by the props of `#[component]` and `#[derive(Props)]`, which
+/// `rsx!` creates through their builder
+pub trait Props {
+    type Builder;
+
+    fn builder() -> Self::Builder;
+}
+
+/// A props builder of a component, in any state
+pub trait Builder {
+    type Component;
+}
+
+/// A props builder, which only builds once every required prop is set
+pub trait Build: Builder {
+    fn build(self) -> Self::Component;
+}
+
+/// How `rsx!` creates a custom component, as picked by [`Probe`]
+#[doc(hidden)]
+pub enum Construct<B, L> {
+    /// Through the props builder that this function returns
+    Builder(fn() -> B),
+    /// With a struct literal, for components that aren't [`Props`]
+    Literal(L),
+}
+
+/// The builder path of components that aren't [`Props`], which is never
+/// taken since this can't be created
+#[doc(hidden)]
+pub struct NoBuilder<T>(Infallible, PhantomData<T>);
+
+impl<T> Builder for NoBuilder<T> {
+    type Component = T;
+}
+
+impl<T> Build for NoBuilder<T> {
+    fn build(self) -> T {
+        match self.0 {}
+    }
+}
+
+/// Picks how `rsx!` creates a component, through [`ConstructBuilder`] for
+/// [`Props`] and [`ConstructLiteral`] for anything else
+#[doc(hidden)]
+pub struct Probe<T>(PhantomData<T>);
+
+impl<T> Probe<T> {
+    /// Takes the component type from the struct pattern of a closure, which
+    /// infers its generic parameters
+    pub fn new(_component: fn(T)) -> Self {
+        Self(PhantomData)
+    }
+
+    /// Passes the component of the struct literal path through, so its type
+    /// is the one the probe was created for
+    pub fn component(&self, component: T) -> T {
+        component
+    }
+}
+
+#[doc(hidden)]
+pub trait ConstructBuilder<B> {
+    fn construct(&self) -> Construct<B, Infallible>;
+}
+
+impl<T: Props> ConstructBuilder<T::Builder> for Probe<T> {
+    fn construct(&self) -> Construct<T::Builder, Infallible> {
+        Construct::Builder(T::builder)
+    }
+}
+
+#[doc(hidden)]
+pub trait ConstructLiteral<B> {
+    fn construct(&self) -> Construct<B, ()>;
+}
+
+impl<T> ConstructLiteral<NoBuilder<T>> for &Probe<T> {
+    fn construct(&self) -> Construct<NoBuilder<T>, ()> {
+        Construct::Literal(())
+    }
+}
+
+/// Returns the marker of the struct literal path, which is uninhabited for
+/// [`Props`]. The compiler then knows the path is unreachable for them, and
+/// doesn't require their omitted props.
+#[doc(hidden)]
+pub fn literal<L>(marker: L) -> L {
+    marker
+}
+
+#[doc(hidden)]
+pub trait FnOutput {
+    type Output;
+}
+
+impl<T> FnOutput for fn() -> T {
+    type Output = T;
+}
+
+/// The `!` type, which can't be named directly on stable Rust
+type Never = <fn() -> ! as FnOutput>::Output;
+
+/// Passes a prop to the struct literal path. [`Props`] get `!` instead, so
+/// their props are only checked by their builder, which may accept other
+/// types through `#[prop(into)]`.
+#[doc(hidden)]
+pub trait LiteralValue<V> {
+    type Value;
+
+    fn value(self, value: V) -> Self::Value;
+}
+
+impl<V> LiteralValue<V> for () {
+    type Value = V;
+
+    fn value(self, value: V) -> V {
+        value
+    }
+}
+
+impl<V> LiteralValue<V> for Infallible {
+    type Value = Never;
+
+    fn value(self, _value: V) -> Never {
+        match self {}
+    }
+}
@@ -0,0 +1,155 @@
+//! The contents of `<script>` and `<style>`, which aren't HTML
+//!
+//! Browsers read everything up to the closing tag of these elements as
+//! text, so escaping it would break the script or stylesheet. Instead,
+//! strings are rendered as-is, and the only sequence that could end the
+//! element early, like `</script`, is neutralized to `<\/script`. In
+//! JavaScript and CSS, `\/` is just an escaped `/`, so the text means the
+//! same.
+
+use crate::Render;
+use std::cell::Cell;
+use std::fmt::{Result, Write};
+
+thread_local! {
+    /// Whether the text being rendered is in a raw text element
+    static RAW_TEXT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether `tag_name` is an element whose contents are raw text
+pub(crate) fn is_raw_text_element(tag_name: &str) -> bool {
+    tag_name.eq_ignore_ascii_case("script") || tag_name.eq_ignore_ascii_case("style")
+}
+
+/// Whether strings should be rendered unescaped
+pub(crate) fn in_raw_text() -> bool {
+    RAW_TEXT.with(Cell::get)
+}
+
+/// Renders the contents of the raw text element `tag_name`
+pub(crate) fn render_raw_text<T: Render, W: Write>(
+    tag_name: &str,
+    contents: T,
+    writer: &mut W,
+) -> Result {
+    let outer = RAW_TEXT.with(|raw_text| raw_text.replace(true));
+    let _restore = Restore(outer);
+
+    let mut raw_text_writer = RawTextWriter::new(tag_name, writer);
+    contents.render_into(&mut raw_text_writer)?;
+    raw_text_writer.finish()
+}
+
+/// Restores the raw text state of the enclosing element, even if rendering
+/// panics
+struct Restore(bool);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        RAW_TEXT.with(|raw_text| raw_text.set(self.0));
+    }
+}
+
+/// Writes text through, rewriting `</tag_name` to `<\/tag_name`, even when
+/// it's split across writes
+pub(crate) struct RawTextWriter<'a, W: Write> {
+    tag_name: &'a str,
+    writer: &'a mut W,
+    /// The start of a closing tag, held back until it's known whether it
+    /// closes `tag_name`
+    pending: String,
+}
+
+impl<'a, W: Write> RawTextWriter<'a, W> {
+    pub(crate) fn new(tag_name: &'a str, writer: &'a mut W) -> Self {
+        Self {
+            tag_name,
+            writer,
+            pending: String::new(),
+        }
+    }
+
+    /// Writes what's held back, once there's nothing left to write
+    pub(crate) fn finish(mut self) -> Result {
+        self.writer.write_str(&std::mem::take(&mut self.pending))
+    }
+
+    /// Whether `c` continues the `</tag_name` in `pending`
+    fn continues_closing_tag(&self, c: char) -> bool {
+        let matched = self.pending.len();
+        match matched {
+            0 => c == '<',
+            1 => c == '/',
+            _ => self.tag_name[matched - 2..]
+                .chars()
+                .next()
+                .is_some_and(|expected| expected.eq_ignore_ascii_case(&c)),
+        }
+    }
+}
+
+impl<W: Write> Write for RawTextWriter<'_, W> {
+    fn write_str(&mut self, text: &str) -> Result {
+        for c in text.chars() {
+            if !self.continues_closing_tag(c) {
+                self.writer.write_str(&std::mem::take(&mut self.pending))?;
+                if c != '<' {
+                    self.writer.write_char(c)?;
+                    continue;
+                }
+            }
+
+            self.pending.push(c);
+
+            if self.pending.len() == self.tag_name.len() + 2 {
+                self.writer.write_str("<\\/")?;
+                self.writer.write_str(&self.pending[2..])?;
+                self.pending.clear();
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn neutralize(tag_name: &str, writes: &[&str]) -> String {
+        let mut output = String::new();
+        let mut writer = RawTextWriter::new(tag_name, &mut output);
+        for text in writes {
+            writer.write_str(text).unwrap();
+        }
+        writer.finish().unwrap();
+        output
+    }
+
+    #[test]
+    fn neutralizes_closing_tags() {
+        assert_eq!(
+            neutralize("script", &["let end = '</script>';"]),
+            r"let end = '<\/script>';"
+        );
+        assert_eq!(
+            neutralize("style", &["</STYLE", "</style"]),
+            r"<\/STYLE<\/style"
+        );
+    }
+
+    #[test]
+    fn neutralizes_closing_tags_split_across_writes() {
+        assert_eq!(neutralize("script", &["<", "/scr", "ipt>"]), r"<\/script>");
+    }
+
+    #[test]
+    fn keeps_other_text() {
+        assert_eq!(
+            neutralize("script", &["a && b < c </div> <</scrip"]),
+            "a && b < c </div> <</scrip"
+        );
+        assert_eq!(neutralize("style", &["</script>"]), "</script>");
+    }
+}
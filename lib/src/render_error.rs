@@ -0,0 +1,116 @@
+//! The error of a failed render
+
+use std::cell::RefCell;
+use std::error::Error;
+use std::fmt;
+
+thread_local! {
+    /// The error raised by a component, until the render it stopped finishes
+    static RAISED: RefCell<Option<RenderError>> = const { RefCell::new(None) };
+}
+
+/// An error that stopped a render
+///
+/// This is either a [`fmt::Error`] of the writer, or the error of a
+/// component that failed, along with that component's name. Components
+/// written with `#[component]` can fail by returning a `Result`:
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// use html_codegen::{component, html, rsx};
+///
+/// #[component]
+/// fn Price<'a>(amount: &'a str) -> Result<_, std::num::ParseIntError> {
+///     let cents: u32 = amount.parse()?;
+///     Ok(rsx! { <span>{cents / 100}{"."}{format!("{:02}", cents % 100)}</span> })
+/// }
+///
+/// let rendered = html! { <Price amount="1250" /> }.unwrap();
+/// let error = html! { <p><Price amount="free" /></p> }.unwrap_err();
+///
+/// assert_eq!(rendered, "<span>12.50</span>");
+/// assert_eq!(error.component_name(), Some("Price"));
+/// assert_eq!(error.to_string(), "failed to render `Price`: invalid digit found in string");
+/// ```
+#[derive(Debug)]
+pub struct RenderError {
+    kind: Kind,
+}
+
+#[derive(Debug)]
+enum Kind {
+    Fmt(fmt::Error),
+    Component {
+        name: &'static str,
+        source: Box<dyn Error + Send + Sync + 'static>,
+    },
+}
+
+impl RenderError {
+    /// The error of the component called `name`
+    pub fn component(name: &'static str, source: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        Self {
+            kind: Kind::Component {
+                name,
+                source: source.into(),
+            },
+        }
+    }
+
+    /// The name of the component that failed, if the render didn't fail
+    /// while writing
+    pub fn component_name(&self) -> Option<&'static str> {
+        match self.kind {
+            Kind::Fmt(_) => None,
+            Kind::Component { name, .. } => Some(name),
+        }
+    }
+
+    /// Keeps the error until the current render finishes, returning the
+    /// [`fmt::Error`] that stops it.
+    ///
+    /// This lets [`Render::render_into`](crate::Render::render_into)
+    /// implementations fail with a `RenderError`, which
+    /// [`Render::try_render_into`](crate::Render::try_render_into) then
+    /// returns instead of the [`fmt::Error`].
+    pub fn raise(self) -> fmt::Error {
+        RAISED.with(|raised| *raised.borrow_mut() = Some(self));
+        fmt::Error
+    }
+
+    /// Runs a render, returning the error raised during it if it fails. An
+    /// error raised by an inner render doesn't leak into the outer one.
+    pub(crate) fn catch(render: impl FnOnce() -> fmt::Result) -> Result<(), Self> {
+        let outer = RAISED.with(|raised| raised.borrow_mut().take());
+        let result = render();
+        let raised = RAISED.with(|raised| std::mem::replace(&mut *raised.borrow_mut(), outer));
+
+        result.map_err(|error| raised.unwrap_or_else(|| error.into()))
+    }
+}
+
+impl From<fmt::Error> for RenderError {
+    fn from(error: fmt::Error) -> Self {
+        Self {
+            kind: Kind::Fmt(error),
+        }
+    }
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            Kind::Fmt(_) => f.write_str("failed to write the rendered output"),
+            Kind::Component { name, source } => write!(f, "failed to render `{name}`: {source}"),
+        }
+    }
+}
+
+impl Error for RenderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            Kind::Fmt(error) => Some(error),
+            Kind::Component { source, .. } => Some(&**source),
+        }
+    }
+}
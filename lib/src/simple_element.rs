@@ -1,5 +1,9 @@
-use crate::html_escaping::escape_html;
-use crate::{Raw, Render};
+use crate::html_escaping::{escape_html, escape_xml_attribute};
+use crate::pretty;
+use crate::raw_text::{is_raw_text_element, render_raw_text};
+use crate::xml::in_xml;
+use crate::{Raw, Render, RenderRef};
+use html_codegen_syntax::is_void_element;
 use ordered_hash_map::OrderedHashMap;
 use std::borrow::Cow;
 use std::fmt::{Result, Write};
@@ -27,9 +31,16 @@ impl<'a> From<AV<'a>> for Option<Cow<'a, str>> {
 }
 
 pub trait ToAttribute<'a> {
+    #[allow(clippy::wrong_self_convention)]
     fn from_value(self) -> AV<'a>;
 }
 
+impl<'a> ToAttribute<'a> for AV<'a> {
+    fn from_value(self) -> AV<'a> {
+        self
+    }
+}
+
 impl<'a> ToAttribute<'a> for Option<Cow<'a, str>> {
     fn from_value(self) -> AV<'a> {
         match self {
@@ -102,7 +113,7 @@ macro_rules! impl_primitive {
         )+
     };
 }
-impl_primitive![u8, u16, u32, u64, i8, i16, i32, i64, f32, f64];
+impl_primitive![u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64];
 
 impl<'a> ToAttribute<'a> for Cow<'a, str> {
     fn from_value(self) -> AV<'a> {
@@ -121,7 +132,7 @@ pub struct SimpleElement<'a, T: Render> {
     pub contents: Option<T>,
 }
 
-fn write_attributes<'a, W: Write>(attributes: Attributes<'a>, writer: &mut W) -> Result {
+fn write_attributes<W: Write>(attributes: &Attributes<'_>, writer: &mut W) -> Result {
     match attributes {
         None => Ok(()),
         Some(attributes) => {
@@ -129,7 +140,11 @@ fn write_attributes<'a, W: Write>(attributes: Attributes<'a>, writer: &mut W) ->
                 match maybe_value {
                     AV::Some(value) => {
                         write!(writer, " {key}=\"")?;
-                        escape_html(&value, writer)?;
+                        if in_xml() {
+                            escape_xml_attribute(value, writer)?;
+                        } else {
+                            escape_html(value, writer)?;
+                        }
                         write!(writer, "\"")?;
                     }
 
@@ -139,6 +154,11 @@ fn write_attributes<'a, W: Write>(attributes: Attributes<'a>, writer: &mut W) ->
                         write!(writer, "\"")?;
                     }
 
+                    // XML attributes always have a value
+                    AV::Short if in_xml() => {
+                        write!(writer, " {key}=\"{key}\"")?;
+                    }
+
                     AV::Short => {
                         write!(writer, " {key}")?;
                     }
@@ -151,31 +171,101 @@ fn write_attributes<'a, W: Write>(attributes: Attributes<'a>, writer: &mut W) ->
     }
 }
 
+/// The length of the attributes as [`write_attributes`] writes them,
+/// without the entities escaping adds
+fn attributes_size_hint(attributes: &Attributes<'_>) -> usize {
+    let Some(attributes) = attributes else {
+        return 0;
+    };
+
+    attributes
+        .iter()
+        .map(|(key, maybe_value)| match maybe_value {
+            AV::Some(value) => key.len() + value.len() + r#" ="""#.len(),
+            AV::SomeRaw(Raw(value)) => key.len() + value.len() + r#" ="""#.len(),
+            AV::Short => key.len() + 1,
+            AV::None => 0,
+        })
+        .sum()
+}
+
+/// Renders an element with `contents`, which the element owns or borrows
+fn render_element<W: Write, C: Render>(
+    tag_name: &str,
+    attributes: &Attributes<'_>,
+    contents: Option<C>,
+    writer: &mut W,
+) -> Result {
+    match contents {
+        None => pretty::render_element(
+            tag_name,
+            writer,
+            |writer| {
+                write!(writer, "<{tag_name}")?;
+                write_attributes(attributes, writer)?;
+
+                if in_xml() {
+                    return write!(writer, "/>");
+                }
+
+                if is_void_element(tag_name) {
+                    // void element, can be self-closing
+                    write!(writer, "/>")
+                } else {
+                    write!(writer, "></{tag_name}>")
+                }
+            },
+            |_| Ok(()),
+            |_| Ok(()),
+        ),
+        Some(renderable) => pretty::render_element(
+            tag_name,
+            writer,
+            |writer| {
+                write!(writer, "<{tag_name}")?;
+                write_attributes(attributes, writer)?;
+                write!(writer, ">")
+            },
+            |writer| {
+                if is_raw_text_element(tag_name) && !in_xml() {
+                    render_raw_text(tag_name, renderable, writer)
+                } else {
+                    renderable.render_into(writer)
+                }
+            },
+            |writer| write!(writer, "</{tag_name}>"),
+        ),
+    }
+}
+
 impl<T: Render> Render for SimpleElement<'_, T> {
     fn render_into<W: Write>(self, writer: &mut W) -> Result {
-        match self.contents {
-            None => {
-                write!(writer, "<{}", self.tag_name)?;
-                write_attributes(self.attributes, writer)?;
-
-                match self.tag_name {
-                    "area" | "base" | "br" | "col" | "embed" | "hr" | "img" | "input" | "link"
-                    | "meta" | "param" | "source" | "track" | "wbr" => {
-                        // void element, can be self-closing
-                        write!(writer, "/>")
-                    }
-                    _ => {
-                        write!(writer, "></{}>", self.tag_name)
-                    }
-                }
-            }
-            Some(renderable) => {
-                write!(writer, "<{}", self.tag_name)?;
-                write_attributes(self.attributes, writer)?;
-                write!(writer, ">")?;
-                renderable.render_into(writer)?;
-                write!(writer, "</{}>", self.tag_name)
-            }
+        render_element(self.tag_name, &self.attributes, self.contents, writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        let start_tag = "<>".len() + self.tag_name.len() + attributes_size_hint(&self.attributes);
+        let end_tag = "</>".len() + self.tag_name.len();
+
+        match &self.contents {
+            None if is_void_element(self.tag_name) => start_tag + "/".len(),
+            None => start_tag + end_tag,
+            Some(contents) => start_tag + contents.size_hint() + end_tag,
         }
     }
 }
+
+impl<T: RenderRef + Render> RenderRef for SimpleElement<'_, T> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        render_element(
+            self.tag_name,
+            &self.attributes,
+            self.contents.as_ref(),
+            writer,
+        )
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.size_hint()
+    }
+}
@@ -0,0 +1,146 @@
+//! Streaming renders, with the `tokio` feature
+//!
+//! [`Render`] writes synchronously, so a streaming render runs on tokio's
+//! blocking threads and sends what it renders in chunks. Only a couple of
+//! chunks are held at a time: the render waits while the response is
+//! slower, so a large page never sits in memory as a whole, and the first
+//! bytes can be sent while the rest is still rendering.
+//!
+//! ```rust
+//! # use pretty_assertions::assert_eq;
+//! use html_codegen::{rsx, Render};
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let rows: Vec<_> = (1..=3).map(|row| rsx! { <li>{row}</li> }).collect();
+//! let page = rsx! { <ul>{rows}</ul> };
+//!
+//! let mut output = Vec::new();
+//! page.render_to_async_write(&mut output).await.unwrap();
+//!
+//! assert_eq!(output, b"<ul><li>1</li><li>2</li><li>3</li></ul>");
+//! # }
+//! ```
+
+use crate::Render;
+use bytes::{Bytes, BytesMut};
+use futures_core::Stream;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio::sync::mpsc;
+
+/// The size of the chunks sent by a streaming render, at most
+pub const CHUNK_SIZE: usize = 8 * 1024;
+
+/// The number of rendered chunks that wait to be read before the render
+/// waits for them
+const BUFFERED_CHUNKS: usize = 2;
+
+/// The [`Bytes`] of a render, in chunks of at most [`CHUNK_SIZE`], as it
+/// renders
+///
+/// This can be the body of a response, like with axum's
+/// `Body::from_stream`. The render stops when the stream is dropped.
+/// A [`RenderError`](crate::RenderError) is the stream's last item, as an
+/// [`io::ErrorKind::Other`] error.
+#[derive(Debug)]
+pub struct RenderStream {
+    chunks: mpsc::Receiver<io::Result<Bytes>>,
+}
+
+impl RenderStream {
+    /// Starts rendering `renderable` on tokio's blocking threads
+    ///
+    /// # Panics
+    ///
+    /// When called outside of a tokio runtime.
+    pub(crate) fn new<T: Render + Send + 'static>(renderable: T) -> Self {
+        let (sender, chunks) = mpsc::channel(BUFFERED_CHUNKS);
+
+        tokio::task::spawn_blocking(move || {
+            let mut writer = ChunkWriter {
+                chunk: BytesMut::with_capacity(CHUNK_SIZE),
+                sender,
+            };
+
+            let result = renderable.try_render_into(&mut writer);
+            let last = match result {
+                Ok(()) if writer.chunk.is_empty() => return,
+                Ok(()) => Ok(writer.chunk.split().freeze()),
+                // the stream was dropped
+                Err(_) if writer.sender.is_closed() => return,
+                Err(error) => Err(io::Error::other(error)),
+            };
+
+            // The stream may have been dropped since, with nothing to tell
+            let _ = writer.sender.blocking_send(last);
+        });
+
+        Self { chunks }
+    }
+}
+
+impl Stream for RenderStream {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.chunks.poll_recv(cx)
+    }
+}
+
+/// Renders `renderable` into `writer`, as it renders, then flushes it
+pub(crate) async fn render_to_async_write<T, W>(renderable: T, mut writer: W) -> io::Result<()>
+where
+    T: Render + Send + 'static,
+    W: AsyncWrite + Unpin,
+{
+    let mut stream = RenderStream::new(renderable);
+
+    while let Some(chunk) = stream.chunks.recv().await {
+        writer.write_all(&chunk?).await?;
+    }
+
+    writer.flush().await
+}
+
+/// Sends what's written in chunks, waiting while the stream is full
+struct ChunkWriter {
+    chunk: BytesMut,
+    sender: mpsc::Sender<io::Result<Bytes>>,
+}
+
+impl fmt::Write for ChunkWriter {
+    fn write_str(&mut self, mut text: &str) -> fmt::Result {
+        while !text.is_empty() {
+            let space = CHUNK_SIZE - self.chunk.len();
+            let len = floor_char_boundary(text, space);
+            self.chunk.extend_from_slice(&text.as_bytes()[..len]);
+            text = &text[len..];
+
+            // a full chunk, or one without room for the next character
+            if self.chunk.len() == CHUNK_SIZE || len == 0 {
+                let chunk = self.chunk.split().freeze();
+                self.sender
+                    .blocking_send(Ok(chunk))
+                    .map_err(|_| fmt::Error)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The largest length of `text` up to `max` that ends on a character
+fn floor_char_boundary(text: &str, max: usize) -> usize {
+    if max >= text.len() {
+        return text.len();
+    }
+
+    (0..=max)
+        .rev()
+        .find(|&index| text.is_char_boundary(index))
+        .unwrap_or(0)
+}
@@ -0,0 +1,234 @@
+//! The inline style attribute value
+
+use crate::html_escaping::escape_css_value;
+use crate::{ToAttribute, AV};
+use std::borrow::Cow;
+
+/// A list of CSS declarations, rendered as a single `style` attribute
+///
+/// This is what `style:property={value}` directives of `rsx!` expand into.
+/// Declarations keep the order in which their property was first set, and
+/// setting a property again replaces its value. `None` values are skipped.
+/// Values are escaped so they can't end their declaration, which makes it
+/// safe to pass user data as a value, but also means a value can't hold a
+/// quoted CSS string. Declarations parsed from a `&str`,
+/// like the literal of a `style="..."` attribute, are written as they are,
+/// like the attribute would be.
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen_macros::html;
+/// use html_codegen::Style;
+///
+/// let accent: Option<&str> = None;
+/// let user_color = "red; position: fixed";
+///
+/// let style = Style::new()
+///     .with("color", "black")
+///     .with("border-color", accent)
+///     .with("margin", "0 auto")
+///     .with("color", user_color);
+///
+/// let result = html! { <div style={style} /> }.unwrap();
+///
+/// assert_eq!(result, r#"<div style="color: red\3b  position: fixed; margin: 0 auto"></div>"#);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Style<'a> {
+    declarations: Vec<Declaration<'a>>,
+}
+
+#[derive(Debug, Clone)]
+struct Declaration<'a> {
+    property: Cow<'a, str>,
+    value: Cow<'a, str>,
+    /// Whether the value is written without escaping, as it's part of a
+    /// declaration block rather than a single value
+    verbatim: bool,
+}
+
+impl<'a> Style<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `property` to `value`, returning the style
+    pub fn with(mut self, property: impl Into<Cow<'a, str>>, value: impl ToStyleValue<'a>) -> Self {
+        self.set(property, value);
+        self
+    }
+
+    /// Sets `property` to `value`, replacing a previous value of that
+    /// property, unless `value` is `None`
+    pub fn set(&mut self, property: impl Into<Cow<'a, str>>, value: impl ToStyleValue<'a>) {
+        if let Some(value) = value.to_style_value() {
+            self.declare(property.into(), value, false);
+        }
+    }
+
+    fn declare(&mut self, property: Cow<'a, str>, value: Cow<'a, str>, verbatim: bool) {
+        let declaration = Declaration {
+            property,
+            value,
+            verbatim,
+        };
+
+        match self
+            .declarations
+            .iter_mut()
+            .find(|previous| previous.property == declaration.property)
+        {
+            Some(previous) => *previous = declaration,
+            None => self.declarations.push(declaration),
+        }
+    }
+
+    /// Sets every declaration of `style`, returning the style
+    pub fn merge(mut self, style: impl ToStyle<'a>) -> Self {
+        style.add_to(&mut self);
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.declarations.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.declarations
+            .iter()
+            .map(|declaration| (declaration.property.as_ref(), declaration.value.as_ref()))
+    }
+}
+
+/// Renders the declarations as `a: b; c: d`, or omits the attribute when empty
+impl<'a> ToAttribute<'a> for Style<'a> {
+    fn from_value(self) -> AV<'a> {
+        if self.is_empty() {
+            return AV::None;
+        }
+
+        let mut rendered = String::new();
+        for (index, declaration) in self.declarations.iter().enumerate() {
+            if index > 0 {
+                rendered.push_str("; ");
+            }
+            rendered.extend(
+                declaration
+                    .property
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric() || *c == '-' || *c == '_'),
+            );
+            rendered.push_str(": ");
+            if declaration.verbatim {
+                rendered.push_str(&declaration.value);
+            } else {
+                escape_css_value(&declaration.value, &mut rendered)
+                    .expect("writing to a String can't fail");
+            }
+        }
+
+        AV::Some(Cow::Owned(rendered))
+    }
+}
+
+/// A value of a single CSS declaration, or `None` to leave it unset
+pub trait ToStyleValue<'a> {
+    fn to_style_value(self) -> Option<Cow<'a, str>>;
+}
+
+impl<'a> ToStyleValue<'a> for &'a str {
+    fn to_style_value(self) -> Option<Cow<'a, str>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl<'a> ToStyleValue<'a> for &'a String {
+    fn to_style_value(self) -> Option<Cow<'a, str>> {
+        Some(Cow::Borrowed(self))
+    }
+}
+
+impl<'a> ToStyleValue<'a> for String {
+    fn to_style_value(self) -> Option<Cow<'a, str>> {
+        Some(Cow::Owned(self))
+    }
+}
+
+impl<'a> ToStyleValue<'a> for Cow<'a, str> {
+    fn to_style_value(self) -> Option<Cow<'a, str>> {
+        Some(self)
+    }
+}
+
+impl<'a, T: ToStyleValue<'a>> ToStyleValue<'a> for Option<T> {
+    fn to_style_value(self) -> Option<Cow<'a, str>> {
+        self.and_then(ToStyleValue::to_style_value)
+    }
+}
+
+/// A value that contributes declarations to a [`Style`]
+pub trait ToStyle<'a> {
+    fn add_to(self, style: &mut Style<'a>);
+}
+
+impl<'a> ToStyle<'a> for Style<'a> {
+    fn add_to(self, style: &mut Style<'a>) {
+        for declaration in self.declarations {
+            style.declare(
+                declaration.property,
+                declaration.value,
+                declaration.verbatim,
+            );
+        }
+    }
+}
+
+/// Parses `a: b; c: d` declarations, skipping the malformed ones. The
+/// values are kept as they are, including the `;` of strings and `url()`s.
+impl<'a> ToStyle<'a> for &'a str {
+    fn add_to(self, style: &mut Style<'a>) {
+        for declaration in split_declarations(self) {
+            if let Some((property, value)) = declaration.split_once(':') {
+                style.declare(property.trim().into(), value.trim().into(), true);
+            }
+        }
+    }
+}
+
+/// Splits a declaration block on the `;` that aren't in a string or
+/// parentheses
+fn split_declarations(block: &str) -> impl Iterator<Item = &str> {
+    let mut quote = None;
+    let mut depth = 0usize;
+    let mut escaped = false;
+    let mut start = 0;
+    let mut declarations = Vec::new();
+
+    for (index, c) in block.char_indices() {
+        match (quote, c) {
+            _ if escaped => escaped = false,
+            (_, '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth = depth.saturating_sub(1),
+            (None, ';') if depth == 0 => {
+                declarations.push(&block[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    declarations.push(&block[start..]);
+    declarations.into_iter()
+}
+
+impl<'a, T: ToStyle<'a>> ToStyle<'a> for Option<T> {
+    fn add_to(self, style: &mut Style<'a>) {
+        if let Some(declarations) = self {
+            declarations.add_to(style);
+        }
+    }
+}
@@ -1,22 +1,63 @@
-use crate::html_escaping::escape_html;
-use crate::Render;
+use crate::html_escaping::{escape_html, escape_xml};
+use crate::raw_text::in_raw_text;
+use crate::xml::in_xml;
+use crate::{Render, RenderRef};
 use std::fmt::{Result, Write};
 
 impl Render for String {
     fn render_into<W: Write>(self, writer: &mut W) -> Result {
-        escape_html(&self, writer)
+        self.as_str().render_into(writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
     }
 }
 
-impl Render for &str {
-    fn render_into<W: Write>(self, writer: &mut W) -> Result {
-        escape_html(self, writer)
+/// Escaped, except in `<script>` and `<style>`, whose contents aren't HTML
+impl RenderRef for str {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        if in_xml() {
+            escape_xml(self, writer)
+        } else if in_raw_text() {
+            writer.write_str(self)
+        } else {
+            escape_html(self, writer)
+        }
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.len()
+    }
+}
+
+impl RenderRef for String {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        self.as_str().render_ref(writer)
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.len()
     }
 }
 
 impl Render for std::borrow::Cow<'_, str> {
     fn render_into<W: Write>(self, writer: &mut W) -> Result {
-        escape_html(&self, writer)
+        self.as_ref().render_into(writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.len()
+    }
+}
+
+impl RenderRef for std::borrow::Cow<'_, str> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        self.as_ref().render_ref(writer)
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.len()
     }
 }
 
@@ -31,10 +72,32 @@ impl<'s> From<&'s str> for Raw<'s> {
 }
 
 /// A raw (unencoded) html string
-impl<'s> Render for Raw<'s> {
+impl Render for Raw<'_> {
     fn render_into<W: Write>(self, writer: &mut W) -> Result {
         write!(writer, "{}", self.0)
     }
+
+    fn size_hint(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl RenderRef for Raw<'_> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        writer.write_str(self.0)
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.0.len()
+    }
+}
+
+/// Creates a raw (unencoded) html string
+#[macro_export]
+macro_rules! raw {
+    ($text:expr) => {
+        ::html_codegen::Raw::from($text)
+    };
 }
 
 #[cfg(test)]
@@ -55,11 +118,3 @@ mod tests {
         assert_eq!(rendered, "<Hello />");
     }
 }
-
-/// Creates a raw (unencoded) html string
-#[macro_export]
-macro_rules! raw {
-    ($text:expr) => {
-        ::html_codegen::Raw::from($text)
-    };
-}
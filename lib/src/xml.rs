@@ -0,0 +1,142 @@
+//! XML utilities
+//!
+//! HTML's rules don't apply to XML documents like sitemaps, feeds or SVG
+//! files, so elements rendered under [`Xml`] follow XML's instead:
+//!
+//! ```rust
+//! # use pretty_assertions::assert_eq;
+//! use html_codegen::html;
+//! use html_codegen::xml::{Xml, XmlDeclaration};
+//!
+//! let result = html! {
+//!     <Xml>
+//!         <XmlDeclaration />
+//!         <feed xmlns="http://www.w3.org/2005/Atom">
+//!             <title>{"Tom & Jerry's"}</title>
+//!             <link href="https://example.com/" />
+//!             <entry />
+//!         </feed>
+//!     </Xml>
+//! }.unwrap();
+//!
+//! assert_eq!(
+//!     result,
+//!     concat!(
+//!         r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+//!         r#"<feed xmlns="http://www.w3.org/2005/Atom">"#,
+//!         "<title>Tom &amp; Jerry&apos;s</title>",
+//!         r#"<link href="https://example.com/"/>"#,
+//!         "<entry/>",
+//!         "</feed>",
+//!     )
+//! );
+//! ```
+
+use crate::{Render, RenderRef};
+use std::cell::Cell;
+use std::fmt::{Result, Write};
+
+thread_local! {
+    /// Whether the elements being rendered are XML
+    static XML: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Whether the elements being rendered are XML
+pub(crate) fn in_xml() -> bool {
+    XML.with(Cell::get)
+}
+
+/// Renders its children as XML
+///
+/// Under `Xml`:
+///
+/// * every element without children self-closes, like `<entry/>`, and
+///   HTML's void elements get a closing tag when they have children, like
+///   the `<link>` of an RSS item
+/// * boolean attributes are written with their name as their value, like
+///   `checked="checked"`
+/// * the contents of `<script>` and `<style>` are escaped like any other
+///   text, rather than being raw text
+/// * text is escaped with [`escape_xml`](crate::html_escaping::escape_xml)
+///   and attribute values with
+///   [`escape_xml_attribute`](crate::html_escaping::escape_xml_attribute).
+///   Literal text and attribute values are escaped when the macro expands,
+///   which is the same for both, except for the whitespace of attributes
+#[derive(Debug, Clone)]
+pub struct Xml<T: Render> {
+    pub children: T,
+}
+
+impl<T: Render> Render for Xml<T> {
+    fn render_into<W: Write>(self, writer: &mut W) -> Result {
+        let outer = XML.with(|xml| xml.replace(true));
+        let _restore = Restore(outer);
+        self.children.render_into(writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.children.size_hint()
+    }
+}
+
+impl<T: RenderRef + Render> RenderRef for Xml<T> {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        let outer = XML.with(|xml| xml.replace(true));
+        let _restore = Restore(outer);
+        self.children.render_ref(writer)
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        self.children.size_hint_ref()
+    }
+}
+
+/// Restores the mode of the enclosing render, even if rendering panics
+struct Restore(bool);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        XML.with(|xml| xml.set(self.0));
+    }
+}
+
+/// XML declaration
+///
+/// ```rust
+/// # use pretty_assertions::assert_eq;
+/// # use html_codegen::xml::{Xml, XmlDeclaration};
+/// # use html_codegen::html;
+/// # let result =
+/// html! {
+///     <Xml>
+///         <XmlDeclaration />
+///         <urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9" />
+///     </Xml>
+/// }.unwrap();
+/// # assert_eq!(
+/// #     result,
+/// #     r#"<?xml version="1.0" encoding="UTF-8"?><urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9"/>"#
+/// # );
+/// ```
+#[derive(Debug, Clone)]
+pub struct XmlDeclaration;
+
+impl Render for XmlDeclaration {
+    fn render_into<W: Write>(self, writer: &mut W) -> Result {
+        self.render_ref(writer)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.size_hint_ref()
+    }
+}
+
+impl RenderRef for XmlDeclaration {
+    fn render_ref<W: Write>(&self, writer: &mut W) -> Result {
+        write!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)
+    }
+
+    fn size_hint_ref(&self) -> usize {
+        r#"<?xml version="1.0" encoding="UTF-8"?>"#.len()
+    }
+}
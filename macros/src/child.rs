@@ -1,10 +1,38 @@
+use crate::comment_child::CommentChild;
 use crate::element::Element;
+use crate::element_attributes::escape_html;
+use crate::for_child::ForChild;
+use crate::format_child::FormatChild;
+use crate::if_child::IfChild;
+use crate::match_child::MatchChild;
+use crate::slot::Slot;
+use proc_macro_error::abort;
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream, Result};
 
 pub enum Child {
     Element(Element),
     RawBlock(syn::Block),
+    /// A bare `"text"` literal
+    Text(syn::LitStr),
+    Format(FormatChild),
+    Comment(CommentChild),
+    If(IfChild),
+    For(ForChild),
+    Match(MatchChild),
+    Slot(Slot),
+}
+
+impl Child {
+    /// The renderable of a child of `<script>` or `<style>`, where text is
+    /// left unescaped for the element to neutralize
+    pub fn to_raw_text_tokens(&self) -> proc_macro2::TokenStream {
+        match self {
+            Self::Text(text) => quote!(#text),
+            Self::Format(format_child) => format_child.to_raw_text_tokens(),
+            child => quote!(#child),
+        }
+    }
 }
 
 impl ToTokens for Child {
@@ -20,12 +48,56 @@ impl ToTokens for Child {
                 };
                 ts.to_tokens(tokens);
             }
+            Self::Text(text) => {
+                let escaped = escape_html(&text.value());
+                quote!(::html_codegen::Raw(#escaped)).to_tokens(tokens);
+            }
+            Self::Format(format_child) => format_child.to_tokens(tokens),
+            Self::Comment(comment_child) => comment_child.to_tokens(tokens),
+            Self::If(if_child) => if_child.to_tokens(tokens),
+            Self::For(for_child) => for_child.to_tokens(tokens),
+            Self::Match(match_child) => match_child.to_tokens(tokens),
+            // Slots of custom components are passed as fields, so any slot
+            // that gets here was written somewhere else.
+            Self::Slot(slot) => abort!(
+                slot.name,
+                "`<slot:{}>` can only be a direct child of a custom component",
+                slot.name
+            ),
         }
     }
 }
 
 impl Parse for Child {
     fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(syn::LitStr) {
+            return Ok(Self::Text(input.parse::<syn::LitStr>()?));
+        }
+
+        if FormatChild::peek(input) {
+            return Ok(Self::Format(input.parse::<FormatChild>()?));
+        }
+
+        if CommentChild::peek(input) {
+            return Ok(Self::Comment(input.parse::<CommentChild>()?));
+        }
+
+        if Slot::peek(input) {
+            return Ok(Self::Slot(input.parse::<Slot>()?));
+        }
+
+        if input.peek(syn::Token![if]) {
+            return Ok(Self::If(input.parse::<IfChild>()?));
+        }
+
+        if input.peek(syn::Token![for]) {
+            return Ok(Self::For(input.parse::<ForChild>()?));
+        }
+
+        if input.peek(syn::Token![match]) {
+            return Ok(Self::Match(input.parse::<MatchChild>()?));
+        }
+
         match input.parse::<Element>() {
             Ok(element) => Ok(Self::Element(element)),
             Err(_) => {
@@ -1,4 +1,5 @@
 use crate::child::Child;
+use crate::slot::Slot;
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream, Result};
 
@@ -12,35 +13,91 @@ impl Children {
         Children { nodes }
     }
 
-    pub fn len(&self) -> usize {
-        self.nodes.len()
+    /// Parses the nodes of a braced control flow body, like `{ <li /> {value} }`.
+    pub fn parse_braced(input: ParseStream) -> Result<Self> {
+        let content;
+        syn::braced!(content in input);
+
+        let mut nodes = vec![];
+        while !content.is_empty() {
+            nodes.push(content.parse::<Child>()?);
+        }
+
+        Ok(Self::new(nodes))
+    }
+
+    pub fn as_tuple_tokens(&self) -> proc_macro2::TokenStream {
+        tuple_tokens(self.nodes.iter().map(|child| quote! { #child }))
     }
 
     pub fn as_option_of_tuples_tokens(&self) -> proc_macro2::TokenStream {
-        let children_quotes: Vec<_> = self
-            .nodes
+        if self.nodes.is_empty() {
+            quote! { Option::<()>::None }
+        } else {
+            let tuple_of_tuples = self.as_tuple_tokens();
+            quote! { Some(#tuple_of_tuples) }
+        }
+    }
+
+    /// Like `as_option_of_tuples_tokens`, for the raw text of `<script>`
+    /// and `<style>`
+    pub fn as_option_of_raw_text_tuples_tokens(&self) -> proc_macro2::TokenStream {
+        if self.nodes.is_empty() {
+            quote! { Option::<()>::None }
+        } else {
+            let tuple_of_tuples = tuple_tokens(self.nodes.iter().map(Child::to_raw_text_tokens));
+            quote! { Some(#tuple_of_tuples) }
+        }
+    }
+
+    /// The `<slot:name>` children, which custom components receive as fields
+    pub fn slots(&self) -> impl Iterator<Item = &Slot> {
+        self.nodes.iter().filter_map(|child| match child {
+            Child::Slot(slot) => Some(slot),
+            _ => None,
+        })
+    }
+
+    fn content(&self) -> impl Iterator<Item = &Child> {
+        self.nodes
             .iter()
-            .map(|child| {
-                quote! { #child }
-            })
-            .collect();
-
-        match children_quotes.len() {
-            0 => quote! { Option::<()>::None },
-            1 => quote! { Some(#(#children_quotes),*) },
-            _ => {
-                let mut iter = children_quotes.iter();
-
-                let first = iter.next().unwrap();
-                let second = iter.next().unwrap();
-
-                let tuple_of_tuples = iter.fold(
-                    quote!((#first, #second)),
-                    |renderable, current| quote!((#renderable, #current)),
-                );
-
-                quote! { Some(#tuple_of_tuples) }
-            }
+            .filter(|child| !matches!(child, Child::Slot(_)))
+    }
+
+    /// Whether there are children other than slots
+    pub fn has_content(&self) -> bool {
+        self.content().next().is_some()
+    }
+
+    /// Like `as_option_of_tuples_tokens`, skipping the slots
+    pub fn content_as_option_of_tuples_tokens(&self) -> proc_macro2::TokenStream {
+        if self.has_content() {
+            let tuple_of_tuples = tuple_tokens(self.content().map(|child| quote! { #child }));
+            quote! { Some(#tuple_of_tuples) }
+        } else {
+            quote! { Option::<()>::None }
+        }
+    }
+}
+
+fn tuple_tokens(
+    children: impl Iterator<Item = proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let children_quotes: Vec<_> = children.collect();
+
+    match children_quotes.len() {
+        0 => quote! { () },
+        1 => quote! { #(#children_quotes),* },
+        _ => {
+            let mut iter = children_quotes.iter();
+
+            let first = iter.next().unwrap();
+            let second = iter.next().unwrap();
+
+            iter.fold(
+                quote!((#first, #second)),
+                |renderable, current| quote!((#renderable, #current)),
+            )
         }
     }
 }
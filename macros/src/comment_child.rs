@@ -0,0 +1,71 @@
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+
+/// A `<!-- "text" -->` or `<!-- {expr} -->` child.
+pub struct CommentChild {
+    pub open_token: syn::Token![<],
+    pub text: CommentText,
+}
+
+pub enum CommentText {
+    Literal(syn::LitStr),
+    Block(syn::Block),
+}
+
+impl CommentChild {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(syn::Token![<]) && input.peek2(syn::Token![!])
+    }
+
+    /// The rendered comment, for literal comments. They're written as they
+    /// are, so they can hold conditional comments like `[if IE]>...<![endif]`.
+    pub fn to_static_html(&self) -> Option<String> {
+        let CommentText::Literal(literal) = &self.text else {
+            return None;
+        };
+
+        Some(format!("<!--{}-->", literal.value()))
+    }
+}
+
+impl Parse for CommentChild {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let open_token = input.parse::<syn::Token![<]>()?;
+        input.parse::<syn::Token![!]>()?;
+        input.parse::<syn::Token![-]>()?;
+        input.parse::<syn::Token![-]>()?;
+
+        let text = if input.peek(syn::LitStr) {
+            CommentText::Literal(input.parse()?)
+        } else {
+            CommentText::Block(input.parse()?)
+        };
+
+        input.parse::<syn::Token![-]>()?;
+        input.parse::<syn::Token![->]>()?;
+
+        Ok(Self { open_token, text })
+    }
+}
+
+impl ToTokens for CommentChild {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        if let Some(html) = self.to_static_html() {
+            return quote! { ::html_codegen::Raw(#html) }.to_tokens(tokens);
+        }
+
+        let text = match &self.text {
+            CommentText::Literal(literal) => quote!(#literal),
+            CommentText::Block(block) => {
+                if block.stmts.len() == 1 {
+                    let first = &block.stmts[0];
+                    quote!(#first)
+                } else {
+                    quote!(#block)
+                }
+            }
+        };
+
+        quote! { ::html_codegen::Comment { text: #text } }.to_tokens(tokens);
+    }
+}
@@ -1,7 +1,10 @@
 use crate::children::Children;
-use crate::element_attribute::ElementAttribute;
-use crate::element_attributes::ElementAttributes;
+use crate::element_attribute::{attribute_name, ElementAttribute};
+use crate::element_attributes::{escape_html, ElementAttributes};
+use crate::format_child::Segment;
+use crate::minify::Minifier;
 use crate::tags::{ClosingTag, OpenTag};
+use proc_macro_error::abort;
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream, Result};
 
@@ -46,10 +49,186 @@ impl Element {
         }
     }
 
-    pub fn into_minimized_formatter(&self) -> proc_macro2::TokenStream {
+    /// The tag name of `<script>` and `<style>`, whose contents are raw
+    /// text rather than HTML
+    pub fn raw_text_tag_name(&self) -> Option<String> {
+        let tag_name = self.name.get_ident()?.to_string();
+        matches!(tag_name.as_str(), "script" | "style").then_some(tag_name)
+    }
+
+    /// The `format_args!` of the element, with its static chunks minified
+    /// when `minify` is set
+    pub fn to_minimized_formatter(&self, minify: bool) -> proc_macro2::TokenStream {
         pub enum Chunk {
             Text(String),
             Value(syn::Block),
+            /// The contents of `<script>` or `<style>`, neutralized when
+            /// formatted since they include values
+            RawText(String, Vec<Chunk>),
+        }
+
+        /// Pushes the children of an element, with their text escaped
+        /// unless it's the raw text of `<script>` or `<style>`
+        fn push_children(
+            element: &Element,
+            raw_text: bool,
+            buffer: &mut String,
+            chunks: &mut Vec<Chunk>,
+        ) {
+            for child in &element.children.nodes {
+                match child {
+                    crate::child::Child::Element(element) => {
+                        let mut child_chunks = into_chunks(element).into_iter();
+
+                        match child_chunks.next() {
+                            Some(Chunk::Text(text)) => {
+                                buffer.push_str(&text);
+                                chunks.push(Chunk::Text(std::mem::take(buffer)));
+                            }
+                            Some(chunk) => {
+                                if !buffer.is_empty() {
+                                    chunks.push(Chunk::Text(std::mem::take(buffer)));
+                                }
+                                chunks.push(chunk);
+                            }
+                            None => {}
+                        }
+
+                        chunks.extend(child_chunks);
+                    }
+
+                    crate::child::Child::RawBlock(block) => match block.stmts.as_slice() {
+                        [syn::Stmt::Expr(syn::Expr::Lit(syn::ExprLit { lit, .. }), None)] => {
+                            push_literal(buffer, lit)
+                        }
+
+                        // note: this was just to experiment
+                        [syn::Stmt::Expr(syn::Expr::Tuple(syn::ExprTuple { elems, .. }), None)] => {
+                            //
+                            // let elements = elems
+                            //     .clone()
+                            //     .into_iter()
+                            //     .map(|expr| format!("{}", expr))
+                            //     .collect::<String>();
+
+                            panic!(":: {}", elems.len());
+                        }
+
+                        _ => {
+                            if !buffer.is_empty() {
+                                chunks.push(Chunk::Text(std::mem::take(buffer)));
+                            }
+
+                            //panic!("{}", block.stmts[0]);
+
+                            chunks.push(Chunk::Value(block.clone()))
+                        }
+                    },
+
+                    crate::child::Child::Text(text) if raw_text => buffer.push_str(&text.value()),
+                    crate::child::Child::Text(text) => buffer.push_str(&escape_html(&text.value())),
+
+                    crate::child::Child::Format(format_child) => {
+                        for segment in &format_child.segments {
+                            match segment {
+                                Segment::Text(text) if raw_text => buffer.push_str(text),
+                                Segment::Text(text) => buffer.push_str(&escape_html(text)),
+                                Segment::Value(value) => {
+                                    if !buffer.is_empty() {
+                                        chunks.push(Chunk::Text(std::mem::take(buffer)));
+                                    }
+                                    chunks.push(Chunk::Value(syn::parse_quote!({ #value })));
+                                }
+                            }
+                        }
+                    }
+
+                    crate::child::Child::Comment(comment_child) => {
+                        match comment_child.to_static_html() {
+                            Some(html) => buffer.push_str(&html),
+                            None => abort!(
+                                comment_child.open_token,
+                                "only literal comments are supported by `html_format!`"
+                            ),
+                        }
+                    }
+
+                    crate::child::Child::Slot(slot) => {
+                        abort!(slot.name, "slots are only supported by `rsx!` and `html!`");
+                    }
+
+                    crate::child::Child::If(if_child) => {
+                        abort!(
+                            if_child.if_token,
+                            "`if` children are only supported by `rsx!` and `html!`"
+                        );
+                    }
+
+                    crate::child::Child::For(for_child) => {
+                        abort!(
+                            for_child.for_token,
+                            "`for` children are only supported by `rsx!` and `html!`"
+                        );
+                    }
+
+                    crate::child::Child::Match(match_child) => {
+                        abort!(
+                            match_child.match_token,
+                            "`match` children are only supported by `rsx!` and `html!`"
+                        );
+                    }
+                }
+            }
+        }
+
+        /// Pushes the contents of `<script>` or `<style>`, which are
+        /// neutralized at compile time when they're all literals
+        fn push_raw_text(
+            tag_name: &str,
+            element: &Element,
+            buffer: &mut String,
+            chunks: &mut Vec<Chunk>,
+        ) {
+            let mut content_buffer = String::new();
+            let mut content = Vec::<Chunk>::new();
+            push_children(element, true, &mut content_buffer, &mut content);
+            content.push(Chunk::Text(content_buffer));
+
+            let texts: Option<String> = content
+                .iter()
+                .map(|chunk| match chunk {
+                    Chunk::Text(text) => Some(text.as_str()),
+                    _ => None,
+                })
+                .collect();
+
+            match texts {
+                Some(text) => buffer.push_str(&neutralize_raw_text(&text, tag_name)),
+                None => {
+                    if !buffer.is_empty() {
+                        chunks.push(Chunk::Text(std::mem::take(buffer)));
+                    }
+                    chunks.push(Chunk::RawText(tag_name.to_string(), content));
+                }
+            }
+        }
+
+        fn format_chunks(chunks: &[Chunk]) -> proc_macro2::TokenStream {
+            let chunk_strs = chunks
+                .iter()
+                .map(|chunk| match chunk {
+                    Chunk::Text(text) => quote! { #text, },
+                    Chunk::Value(value) => quote! { #value, },
+                    Chunk::RawText(tag_name, content) => {
+                        let content = format_chunks(content);
+                        quote! { ::html_codegen::html_escaping::RawText::new(#tag_name, #content), }
+                    }
+                })
+                .collect::<proc_macro2::TokenStream>();
+
+            let expr = (0..chunks.len()).map(|_| "{}").collect::<String>();
+
+            quote! { format_args!( #expr, #chunk_strs ) }
         }
 
         fn into_chunks(element: &Element) -> Vec<Chunk> {
@@ -59,76 +238,74 @@ impl Element {
             let tag_name = element.name.get_ident().map(|ident| ident.to_string()); //.expect("valid tag name");
 
             if let Some(tag_name) = tag_name.as_deref() {
-                buffer.push_str("<");
+                buffer.push('<');
                 buffer.push_str(tag_name);
 
                 // todo: ignore, fail, or otherwise handle attrs on empty tag better
 
+                if let Some(spread) = element.attributes.spreads.first() {
+                    abort!(
+                        spread.value,
+                        "attribute spreads are only supported by `rsx!` and `html!`"
+                    );
+                }
+
                 for attribute in &element.attributes.attributes {
                     //let key = attribute.ident();
 
                     match attribute {
                         ElementAttribute::Punned(key) => {
-                            let attr_name = key
-                                .clone()
-                                .into_iter()
-                                .map(|ident| format!("{}", ident))
-                                .collect::<String>();
+                            let attr_name = attribute_name(key);
 
-                            buffer.push_str(" ");
+                            buffer.push(' ');
                             buffer.push_str(attr_name.as_str());
                         }
+                        ElementAttribute::Directive(directive, _) => {
+                            abort!(
+                                directive,
+                                "`{}:` directives are only supported by `rsx!` and `html!`",
+                                directive
+                            );
+                        }
+                        ElementAttribute::Literal(key, lit) => {
+                            let attr_name = attribute_name(key);
+
+                            if let syn::Lit::Bool(flag) = lit {
+                                if flag.value {
+                                    buffer.push(' ');
+                                    buffer.push_str(attr_name.as_str());
+                                }
+                                continue;
+                            }
+
+                            buffer.push(' ');
+                            buffer.push_str(attr_name.as_str());
+                            buffer.push_str("=\"");
+                            push_escaped_literal(&mut buffer, lit);
+                            buffer.push('"');
+                        }
                         ElementAttribute::WithValue(key, block) => {
-                            let attr_name = key
-                                .clone()
-                                .into_iter()
-                                .map(|ident| format!("{}", ident))
-                                .collect::<String>();
+                            let attr_name = attribute_name(key);
 
-                            buffer.push_str(" ");
+                            buffer.push(' ');
                             buffer.push_str(attr_name.as_str());
                             buffer.push_str("=\"");
 
                             match block.stmts.as_slice() {
                                 [syn::Stmt::Expr(syn::Expr::Lit(syn::ExprLit { lit, .. }), None)] => {
-                                    match lit {
-                                        syn::Lit::Str(x) => {
-                                            buffer.push_str(&format!("{}", x.value()))
-                                        }
-                                        syn::Lit::ByteStr(x) => buffer.push_str(&format!(
-                                            "{}",
-                                            String::from_utf8(x.value()).expect("valid bytestr")
-                                        )),
-                                        syn::Lit::Byte(x) => {
-                                            buffer.push_str(&format!("{}", x.value()))
-                                        }
-                                        syn::Lit::Char(x) => {
-                                            buffer.push_str(&format!("{}", x.value()))
-                                        }
-                                        syn::Lit::Int(x) => {
-                                            buffer.push_str(&format!("{}", x.base10_digits()))
-                                        }
-                                        syn::Lit::Float(x) => {
-                                            buffer.push_str(&format!("{}", x.base10_digits()))
-                                        }
-                                        syn::Lit::Bool(x) => {
-                                            buffer.push_str(&format!("{}", x.value()))
-                                        }
-                                        syn::Lit::Verbatim(_) => {}
-                                        _ => {}
-                                    }
+                                    push_literal(&mut buffer, lit)
                                 }
 
                                 _ => {
                                     // flush buffer as text block, next is expr
                                     // todo: necessary? if buffer.len() > 0 {
-                                    chunks.push(Chunk::Text(buffer.drain(..).collect()));
+                                    chunks.push(Chunk::Text(std::mem::take(&mut buffer)));
                                     // }
                                     chunks.push(Chunk::Value(block.clone()));
                                 }
                             }
 
-                            buffer.push_str("\"");
+                            buffer.push('"');
                         }
                     }
                 }
@@ -141,86 +318,21 @@ impl Element {
                 }
             } else {
                 if tag_name.is_some() {
-                    buffer.push_str(">");
+                    buffer.push('>');
                 }
 
-                for child in &element.children.nodes {
-                    match child {
-                        crate::child::Child::Element(element) => {
-                            let mut child_chunks = into_chunks(element).into_iter();
-
-                            match child_chunks.next() {
-                                Some(Chunk::Text(text)) => {
-                                    buffer.push_str(&text);
-                                    chunks.push(Chunk::Text(buffer.drain(..).collect()));
-                                }
-                                Some(Chunk::Value(value)) => {
-                                    if buffer.len() > 0 {
-                                        chunks.push(Chunk::Text(buffer.drain(..).collect()));
-                                    }
-                                    chunks.push(Chunk::Value(value));
-                                }
-                                None => {}
-                            }
-
-                            chunks.extend(child_chunks);
-                        }
-
-                        crate::child::Child::RawBlock(block) => match block.stmts.as_slice() {
-                            [syn::Stmt::Expr(syn::Expr::Lit(syn::ExprLit { lit, .. }), None)] => {
-                                match lit {
-                                    syn::Lit::Str(x) => buffer.push_str(&format!("{}", x.value())),
-                                    syn::Lit::ByteStr(x) => buffer.push_str(&format!(
-                                        "{}",
-                                        String::from_utf8(x.value()).expect("valid bytestr")
-                                    )),
-                                    syn::Lit::Byte(x) => buffer.push_str(&format!("{}", x.value())),
-                                    syn::Lit::Char(x) => buffer.push_str(&format!("{}", x.value())),
-                                    syn::Lit::Int(x) => {
-                                        buffer.push_str(&format!("{}", x.base10_digits()))
-                                    }
-                                    syn::Lit::Float(x) => {
-                                        buffer.push_str(&format!("{}", x.base10_digits()))
-                                    }
-                                    syn::Lit::Bool(x) => buffer.push_str(&format!("{}", x.value())),
-                                    syn::Lit::Verbatim(_) => {}
-                                    _ => todo!(),
-                                }
-                            }
-
-                            // note: this was just to experiment
-                            [syn::Stmt::Expr(
-                                syn::Expr::Tuple(syn::ExprTuple { elems, .. }),
-                                None,
-                            )] => {
-                                //
-                                // let elements = elems
-                                //     .clone()
-                                //     .into_iter()
-                                //     .map(|expr| format!("{}", expr))
-                                //     .collect::<String>();
-
-                                panic!(":: {}", elems.len());
-                            }
-
-                            _ => {
-                                if buffer.len() > 0 {
-                                    chunks.push(Chunk::Text(buffer.drain(..).collect()));
-                                }
-
-                                //panic!("{}", block.stmts[0]);
-
-                                chunks.push(Chunk::Value(block.clone()))
-                            }
-                        },
+                match element.raw_text_tag_name() {
+                    Some(raw_text_tag) => {
+                        push_raw_text(&raw_text_tag, element, &mut buffer, &mut chunks)
                     }
+                    None => push_children(element, false, &mut buffer, &mut chunks),
                 }
                 // children stuff
 
                 if let Some(tag_name) = tag_name.as_deref() {
                     buffer.push_str("</");
                     buffer.push_str(tag_name);
-                    buffer.push_str(">");
+                    buffer.push('>');
                 }
             }
 
@@ -228,36 +340,99 @@ impl Element {
             chunks
         }
 
+        /// Minifies the static chunks, leaving the values between them and
+        /// the contents of `<script>` and `<style>` as they are
+        fn minify_chunks(chunks: Vec<Chunk>) -> Vec<Chunk> {
+            let mut minifier = Minifier::default();
+            let mut minified = Vec::<Chunk>::new();
+            let mut buffer = String::new();
+
+            for chunk in chunks {
+                if let Chunk::Text(text) = chunk {
+                    buffer.push_str(&minifier.chunk(&text));
+                    continue;
+                }
+
+                buffer.push_str(&minifier.before_value());
+                if !buffer.is_empty() {
+                    minified.push(Chunk::Text(std::mem::take(&mut buffer)));
+                }
+                minified.push(chunk);
+            }
+
+            buffer.push_str(&minifier.finish());
+            minified.push(Chunk::Text(buffer));
+            minified
+        }
+
         let chunks = into_chunks(self);
-        let chunks_len = chunks.len();
-
-        let chunk_strs = chunks
-            .iter()
-            .map(|chunk| match chunk {
-                Chunk::Text(text) => quote! { #text, },
-                Chunk::Value(value) => quote! { #value, },
-            })
-            .collect::<proc_macro2::TokenStream>();
-
-        let expr = (0..chunks_len)
-            .into_iter()
-            .map(|_| "{}")
-            .collect::<String>();
-
-        quote! { format_args!( #expr, #chunk_strs ) }
+        if minify {
+            format_chunks(&minify_chunks(chunks))
+        } else {
+            format_chunks(&chunks)
+        }
+    }
+}
+
+/// Mirrors `html_codegen::html_escaping::RawText`, for raw text known at
+/// compile time: rewrites `</tag_name` to `<\/tag_name` so it can't close
+/// the element early.
+fn neutralize_raw_text(text: &str, tag_name: &str) -> String {
+    let closing_tag = format!("</{tag_name}");
+    let mut neutralized = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(index) = rest.to_ascii_lowercase().find(&closing_tag) {
+        neutralized.push_str(&rest[..index]);
+        neutralized.push_str("<\\/");
+        rest = &rest[index + 2..];
+    }
+
+    neutralized.push_str(rest);
+    neutralized
+}
+
+/// Appends the text form of a literal to a static formatter chunk.
+fn push_literal(buffer: &mut String, lit: &syn::Lit) {
+    match lit {
+        syn::Lit::Str(x) => buffer.push_str(&x.value()),
+        syn::Lit::ByteStr(x) => match String::from_utf8(x.value()) {
+            Ok(text) => buffer.push_str(&text),
+            Err(_) => abort!(x, "Byte string literals must be valid UTF-8"),
+        },
+        syn::Lit::Byte(x) => buffer.push_str(&x.value().to_string()),
+        syn::Lit::Char(x) => buffer.push(x.value()),
+        syn::Lit::Int(x) => buffer.push_str(x.base10_digits()),
+        syn::Lit::Float(x) => buffer.push_str(x.base10_digits()),
+        syn::Lit::Bool(x) => buffer.push_str(&x.value().to_string()),
+        lit => abort!(
+            lit,
+            "Unsupported literal, expected a string, number, char or bool"
+        ),
     }
 }
 
+/// Appends the text form of a literal escaped, the way `html!` writes it.
+fn push_escaped_literal(buffer: &mut String, lit: &syn::Lit) {
+    let mut text = String::new();
+    push_literal(&mut text, lit);
+    buffer.push_str(&escape_html(&text));
+}
+
 impl ToTokens for Element {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let name = &self.name;
 
         let declaration = if self.is_custom_element() {
-            let attrs = self.attributes.for_custom_element(&self.children);
-            quote! { #name #attrs }
+            let attrs = self.attributes.for_custom_element(name, &self.children);
+            quote! { #attrs }
         } else {
             let attrs = self.attributes.for_simple_element();
-            let children_tuple = self.children.as_option_of_tuples_tokens();
+            let children_tuple = if self.raw_text_tag_name().is_some() {
+                self.children.as_option_of_raw_text_tuples_tokens()
+            } else {
+                self.children.as_option_of_tuples_tokens()
+            };
             quote! {
                 ::html_codegen::SimpleElement {
                     tag_name: stringify!(#name),
@@ -1,20 +1,153 @@
-use quote::quote;
+use proc_macro2::{Punct, TokenStream};
+use quote::{quote, ToTokens, TokenStreamExt};
 use std::hash::{Hash, Hasher};
 use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream, Result};
 use syn::spanned::Spanned;
 
-pub type AttributeKey = syn::punctuated::Punctuated<syn::Ident, syn::Token![-]>;
+/// The name of an attribute: words separated by `-`, `:`, `::` or `.`, which
+/// may start with an `@` or `:` prefix, like `data-id`, `xlink:href` or
+/// `@click.prevent`
+#[derive(Clone)]
+pub struct AttributeKey {
+    /// Each word, with the prefix or separator written before it
+    parts: Vec<(Vec<Punct>, syn::Ident)>,
+}
+
+impl AttributeKey {
+    pub fn iter(&self) -> impl Iterator<Item = &syn::Ident> {
+        self.parts.iter().map(|(_, word)| word)
+    }
+
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Whether the name is made of words separated by dashes only, like the
+    /// names of Rust identifiers and `data-*` attributes
+    pub fn is_dashed(&self) -> bool {
+        self.parts.iter().enumerate().all(|(index, (puncts, _))| {
+            puncts.iter().all(|punct| punct.as_char() == '-') && (index > 0 || puncts.is_empty())
+        })
+    }
+
+    /// Parses the punctuation before a word, if a word follows it
+    fn parse_separator(input: ParseStream, first: bool) -> Result<Option<Vec<Punct>>> {
+        let separator = if first {
+            if input.peek(syn::Token![@]) || input.peek(syn::Token![:]) {
+                1
+            } else {
+                0
+            }
+        } else if input.peek(syn::Token![::]) {
+            2
+        } else if input.peek(syn::Token![-])
+            || input.peek(syn::Token![:])
+            || input.peek(syn::Token![.])
+        {
+            1
+        } else {
+            return Ok(None);
+        };
+
+        let fork = input.fork();
+        for _ in 0..separator {
+            fork.parse::<Punct>()?;
+        }
+        if !fork.peek(syn::Ident::peek_any) {
+            return Ok(None);
+        }
+
+        (0..separator)
+            .map(|_| input.parse::<Punct>())
+            .collect::<Result<_>>()
+            .map(Some)
+    }
+}
+
+impl Parse for AttributeKey {
+    /// Parses a name, stopping before the `:` of a `directive:`
+    fn parse(input: ParseStream) -> Result<Self> {
+        let prefix = Self::parse_separator(input, true)?.unwrap_or_default();
+        let first = syn::Ident::parse_any(input)?;
+        let is_directive = prefix.is_empty() && DIRECTIVES.iter().any(|known| first == known);
+        let mut parts = vec![(prefix, first)];
+
+        if is_directive && input.peek(syn::Token![:]) && !input.peek(syn::Token![::]) {
+            return Ok(Self { parts });
+        }
+
+        while let Some(separator) = Self::parse_separator(input, false)? {
+            parts.push((separator, syn::Ident::parse_any(input)?));
+        }
+
+        Ok(Self { parts })
+    }
+}
+
+impl ToTokens for AttributeKey {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        for (puncts, word) in &self.parts {
+            tokens.append_all(puncts);
+            word.to_tokens(tokens);
+        }
+    }
+}
+
+/// Checks an attribute starts here, from its first word or its prefix
+pub fn peek_attribute_key(input: ParseStream) -> bool {
+    input.peek(syn::Ident::peek_any)
+        || ((input.peek(syn::Token![@])
+            || (input.peek(syn::Token![:]) && !input.peek(syn::Token![::])))
+            && input.peek2(syn::Ident::peek_any))
+}
 
 pub enum ElementAttribute {
     Punned(AttributeKey),
     WithValue(AttributeKey, syn::Block),
+    /// A literal value written without braces, like `id="main"` or
+    /// `tabindex=-1`, which is rendered at compile time
+    Literal(AttributeKey, syn::Lit),
+    /// A `directive:name={value}` attribute, like `class:active={is_active}`
+    Directive(syn::Ident, Box<ElementAttribute>),
+}
+
+/// The rendered name of an attribute, as written but without `r#` prefixes
+pub fn attribute_name(key: &AttributeKey) -> String {
+    key.parts
+        .iter()
+        .map(|(puncts, word)| {
+            let mut part: String = puncts.iter().map(Punct::as_char).collect();
+            part.push_str(&word.unraw().to_string());
+            part
+        })
+        .collect()
 }
 
+/// The `directive:` prefixes known to the macros
+pub const DIRECTIVES: &[&str] = &["class", "style"];
+
 impl ElementAttribute {
     pub fn ident(&self) -> &AttributeKey {
         match self {
-            Self::Punned(ident) | Self::WithValue(ident, _) => ident,
+            Self::Punned(ident) | Self::WithValue(ident, _) | Self::Literal(ident, _) => ident,
+            Self::Directive(_, attribute) => attribute.ident(),
+        }
+    }
+
+    pub fn directive(&self) -> Option<&syn::Ident> {
+        match self {
+            Self::Directive(directive, _) => Some(directive),
+            _ => None,
+        }
+    }
+
+    /// Whether this attribute contributes to the rendered `name` attribute,
+    /// either directly or through a `name:` directive
+    pub fn targets(&self, name: &str) -> bool {
+        match self.directive() {
+            Some(directive) => directive == name,
+            None => attribute_name(self.ident()) == name,
         }
     }
 
@@ -22,6 +155,28 @@ impl ElementAttribute {
         self.ident().iter().collect::<Vec<_>>()
     }
 
+    /// The value of the attribute, when it is a single literal like
+    /// `id="main"` or `id={"main"}`
+    pub fn literal(&self) -> Option<&syn::Lit> {
+        match self {
+            Self::WithValue(_, value) => match value.stmts.as_slice() {
+                [syn::Stmt::Expr(syn::Expr::Lit(syn::ExprLit { lit, .. }), None)] => Some(lit),
+                _ => None,
+            },
+            Self::Literal(_, lit) => Some(lit),
+            _ => None,
+        }
+    }
+
+    /// The value of the attribute, when it is a literal written without
+    /// braces like `id="main"`. Braced values keep rendering at runtime.
+    pub fn unbraced_literal(&self) -> Option<&syn::Lit> {
+        match self {
+            Self::Literal(_, lit) => Some(lit),
+            _ => None,
+        }
+    }
+
     pub fn value_tokens(&self) -> proc_macro2::TokenStream {
         match self {
             Self::WithValue(_, value) => {
@@ -33,6 +188,8 @@ impl ElementAttribute {
                 }
             }
             Self::Punned(ident) => quote!(#ident),
+            Self::Literal(_, lit) => quote!(#lit),
+            Self::Directive(_, attribute) => attribute.value_tokens(),
         }
     }
 
@@ -45,7 +202,21 @@ impl ElementAttribute {
     }
 
     pub fn validate_for_custom_element(self) -> Result<Self> {
-        if self.idents().len() < 2 {
+        if let Some(directive) = self.directive() {
+            let error_message = format!(
+                "`{}:` directives are only supported on HTML elements",
+                directive
+            );
+
+            Err(syn::Error::new(directive.span(), error_message))
+        } else if !self.ident().is_dashed() {
+            let error_message = format!(
+                "Can't use `{}` on custom components, props must be Rust identifiers",
+                attribute_name(self.ident())
+            );
+
+            Err(syn::Error::new(self.ident().span(), error_message))
+        } else if self.idents().len() < 2 {
             Ok(self)
         } else {
             let alternative_name = self
@@ -65,7 +236,16 @@ impl ElementAttribute {
     }
 
     pub fn validate_for_simple_element(self) -> Result<Self> {
+        if let Self::Directive(directive, attribute) = self {
+            let attribute = attribute.validate_for_simple_element()?;
+            return Ok(Self::Directive(directive, Box::new(attribute)));
+        }
+
         match (&self, self.idents().len()) {
+            (Self::Punned(ref key), _) if !key.is_dashed() => {
+                let error_message = "Can't use punning with namespaced or prefixed values";
+                Err(syn::Error::new(key.span(), error_message))
+            }
             (Self::Punned(ref key), len) if len > 1 => {
                 let error_message = "Can't use punning with dash-delimited values";
                 Err(syn::Error::new(key.span(), error_message))
@@ -77,9 +257,8 @@ impl ElementAttribute {
 
 impl PartialEq for ElementAttribute {
     fn eq(&self, other: &Self) -> bool {
-        let self_idents: Vec<_> = self.ident().iter().collect();
-        let other_idents: Vec<_> = other.ident().iter().collect();
-        self_idents == other_idents
+        self.directive() == other.directive()
+            && attribute_name(self.ident()) == attribute_name(other.ident())
     }
 }
 
@@ -87,14 +266,29 @@ impl Eq for ElementAttribute {}
 
 impl Hash for ElementAttribute {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        let ident = self.idents();
-        Hash::hash(&ident, state)
+        Hash::hash(&self.directive(), state);
+        Hash::hash(&attribute_name(self.ident()), state)
     }
 }
 
 impl Parse for ElementAttribute {
     fn parse(input: ParseStream) -> Result<Self> {
-        let name = AttributeKey::parse_separated_nonempty_with(input, syn::Ident::parse_any)?;
+        let name = input.parse::<AttributeKey>()?;
+
+        if name.len() == 1 && input.peek(syn::Token![:]) && !input.peek(syn::Token![::]) {
+            let directive = name.iter().next().unwrap().clone();
+            input.parse::<syn::Token![:]>()?;
+            let attribute = input.parse::<ElementAttribute>()?;
+            if attribute.directive().is_some() {
+                return Err(syn::Error::new(
+                    attribute.ident().span(),
+                    "Directives can't be nested",
+                ));
+            }
+
+            return Ok(Self::Directive(directive, Box::new(attribute)));
+        }
+
         let not_punned = input.peek(syn::Token![=]);
 
         if !not_punned {
@@ -102,10 +296,27 @@ impl Parse for ElementAttribute {
         }
 
         input.parse::<syn::Token![=]>()?;
-        // TODO: Add support for literals
         // TODO: Add support for bool expr disable attribute
-        let value = input.parse::<syn::Block>()?;
+        if input.peek(syn::Lit) {
+            return Ok(Self::Literal(name, input.parse()?));
+        }
+
+        if input.peek(syn::Token![-]) && input.peek2(syn::Lit) {
+            input.parse::<syn::Token![-]>()?;
+            let lit = match input.parse::<syn::Lit>()? {
+                syn::Lit::Int(number) => syn::Lit::Int(syn::LitInt::new(
+                    &format!("-{}", number.token()),
+                    number.span(),
+                )),
+                syn::Lit::Float(number) => syn::Lit::Float(syn::LitFloat::new(
+                    &format!("-{}", number.token()),
+                    number.span(),
+                )),
+                lit => return Err(syn::Error::new(lit.span(), "Only numbers can be negative")),
+            };
+            return Ok(Self::Literal(name, lit));
+        }
 
-        Ok(Self::WithValue(name, value))
+        Ok(Self::WithValue(name, input.parse::<syn::Block>()?))
     }
 }
@@ -1,30 +1,67 @@
 use crate::children::Children;
-use crate::element_attribute::ElementAttribute;
+use crate::element_attribute::{attribute_name, peek_attribute_key, ElementAttribute, DIRECTIVES};
 use ordered_hash_map::OrderedHashSet;
+use proc_macro2::{Delimiter, Spacing, Span};
 use proc_macro_error::emit_error;
-use quote::{quote, ToTokens};
-use syn::ext::IdentExt;
+use quote::{format_ident, quote, quote_spanned, ToTokens};
 use syn::parse::{Parse, ParseStream, Result};
 use syn::spanned::Spanned;
 
 pub type Attributes = OrderedHashSet<ElementAttribute>;
 
+/// An `{..value}` attribute spread, applied after the first `position`
+/// attributes of the element.
+pub struct AttributeSpread {
+    pub position: usize,
+    pub value: syn::Expr,
+}
+
+impl AttributeSpread {
+    fn peek(input: ParseStream) -> bool {
+        let Some((content, _, _)) = input.cursor().group(Delimiter::Brace) else {
+            return false;
+        };
+
+        matches!(
+            content.punct(),
+            Some((dot, rest)) if dot.as_char() == '.' && dot.spacing() == Spacing::Joint
+                && matches!(rest.punct(), Some((dot, _)) if dot.as_char() == '.')
+        )
+    }
+
+    fn parse(input: ParseStream, position: usize) -> Result<Self> {
+        let content;
+        syn::braced!(content in input);
+        content.parse::<syn::Token![..]>()?;
+        let value = content.parse::<syn::Expr>()?;
+
+        Ok(Self { position, value })
+    }
+}
+
 #[derive(Default)]
 pub struct ElementAttributes {
     pub attributes: Attributes,
+    pub spreads: Vec<AttributeSpread>,
 }
 
 impl ElementAttributes {
-    pub fn new(attributes: Attributes) -> Self {
-        Self { attributes }
+    pub fn new(attributes: Attributes, spreads: Vec<AttributeSpread>) -> Self {
+        Self {
+            attributes,
+            spreads,
+        }
     }
 
     pub fn for_custom_element<'c>(
         &self,
+        name: &'c syn::Path,
         children: &'c Children,
     ) -> CustomElementAttributes<'_, 'c> {
         CustomElementAttributes {
+            name,
             attributes: &self.attributes,
+            spreads: &self.spreads,
             children,
         }
     }
@@ -32,6 +69,7 @@ impl ElementAttributes {
     pub fn for_simple_element(&self) -> SimpleElementAttributes<'_> {
         SimpleElementAttributes {
             attributes: &self.attributes,
+            spreads: &self.spreads,
         }
     }
 
@@ -50,95 +88,334 @@ impl ElementAttributes {
             })
             .collect();
 
-        Ok(ElementAttributes::new(new_attributes))
+        if is_custom_element {
+            if let Some(extra_spread) = parsed_self.spreads.get(1) {
+                emit_error!(
+                    extra_spread.value.span(),
+                    "Custom components accept a single `{..props}` spread"
+                );
+            }
+        }
+
+        Ok(ElementAttributes::new(new_attributes, parsed_self.spreads))
     }
 }
 
 impl Parse for ElementAttributes {
     fn parse(input: ParseStream) -> Result<Self> {
         let mut attributes: OrderedHashSet<ElementAttribute> = OrderedHashSet::new();
-        while input.peek(syn::Ident::peek_any) {
+        let mut spreads = vec![];
+        loop {
+            if AttributeSpread::peek(input) {
+                spreads.push(AttributeSpread::parse(input, attributes.len())?);
+                continue;
+            }
+
+            if !peek_attribute_key(input) {
+                break;
+            }
+
             let attribute = input.parse::<ElementAttribute>()?;
             let ident = attribute.ident();
             if attributes.contains(&attribute) {
                 emit_error!(
                     ident.span(),
                     "There is a previous definition of the {} attribute",
-                    quote!(#ident)
+                    attribute_name(ident)
                 );
             }
             attributes.insert(attribute);
         }
-        Ok(ElementAttributes::new(attributes))
+        Ok(ElementAttributes::new(attributes, spreads))
     }
 }
 
+/// Creates a custom component through its props builder, or with a struct
+/// literal when it has none or its props are spread
 pub struct CustomElementAttributes<'a, 'c> {
+    name: &'c syn::Path,
     attributes: &'a Attributes,
+    spreads: &'a [AttributeSpread],
     children: &'c Children,
 }
 
-impl<'a, 'c> ToTokens for CustomElementAttributes<'a, 'c> {
+impl ToTokens for CustomElementAttributes<'_, '_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let mut attrs: Vec<_> = self
+        let name = self.name;
+
+        let mut props: Vec<_> = self
             .attributes
             .iter()
             .map(|attribute| {
-                let ident = attribute.ident();
-                let value = attribute.value_tokens();
-
-                quote! {
-                    #ident: #value
-                }
+                (
+                    attribute.ident().to_token_stream(),
+                    attribute.value_tokens(),
+                )
             })
             .collect();
 
-        if self.children.len() > 0 {
-            let children_tuple = self.children.as_option_of_tuples_tokens();
-            attrs.push(quote! {
-                children: #children_tuple
-            });
+        if self.children.has_content() {
+            let children_tuple = self.children.content_as_option_of_tuples_tokens();
+            props.push((quote!(children), children_tuple));
         }
 
-        let quoted = if attrs.len() == 0 {
-            quote!()
-        } else {
-            quote!({ #(#attrs),* })
+        let mut slot_names: Vec<&syn::Ident> = vec![];
+        for slot in self.children.slots() {
+            if slot_names.contains(&&slot.name) {
+                emit_error!(
+                    slot.name.span(),
+                    "The `{}` slot is already filled",
+                    slot.name
+                );
+                continue;
+            }
+            slot_names.push(&slot.name);
+
+            let slot_tuple = slot.children.as_option_of_tuples_tokens();
+            props.push((slot.name.to_token_stream(), slot_tuple));
+        }
+
+        let quoted = match self.spreads.first() {
+            Some(AttributeSpread { value, .. }) => {
+                let fields = props.iter().map(|(ident, value)| quote!(#ident: #value));
+                quote!(#name { #(#fields,)* ..#value })
+            }
+            None => construct_tokens(name, &props),
         };
 
         quoted.to_tokens(tokens);
     }
 }
 
+/// Creates a custom component through its props builder when it's `Props`,
+/// which `#[component]` and `#[derive(Props)]` implement, and with a struct
+/// literal otherwise.
+///
+/// Both paths are type checked, so the props are evaluated once beforehand,
+/// and the path that isn't taken gets an uninhabited marker that makes the
+/// compiler skip it:
+/// - The local `Setters` give any builder a setter for every prop, which
+///   leads nowhere, so only the struct literal reports unknown props.
+/// - The struct literal takes the omitted fields from an uninitialized
+///   binding, so they are only required when the component isn't `Props`,
+///   and its values are `!` when it is, so props that the builder converts
+///   `Into` their type aren't rejected.
+fn construct_tokens(
+    name: &syn::Path,
+    props: &[(proc_macro2::TokenStream, proc_macro2::TokenStream)],
+) -> proc_macro2::TokenStream {
+    let values = props.iter().map(|(_, value)| value);
+    let bindings: Vec<_> = (0..props.len())
+        .map(|index| format_ident!("prop_{}", index, span = Span::mixed_site()))
+        .collect();
+    let fields: Vec<_> = props.iter().map(|(ident, _)| ident).collect();
+    let setters = fields
+        .iter()
+        .zip(&bindings)
+        .map(|(ident, binding)| quote_spanned!(ident.span()=> .#ident(#binding)));
+
+    let mut setter_names: Vec<String> = vec![];
+    let fallback_setters = fields.iter().filter_map(|ident| {
+        let setter_name = ident.to_string();
+        if setter_names.contains(&setter_name) {
+            return None;
+        }
+        setter_names.push(setter_name);
+        Some(quote! {
+            fn #ident<V>(self, _: V) -> ::html_codegen::props::NoBuilder<Self::Component> {
+                ::core::unreachable!()
+            }
+        })
+    });
+
+    let probe = quote_spanned!(Span::mixed_site()=> probe);
+    let builder = quote_spanned!(Span::mixed_site().located_at(name.span())=> builder);
+    let marker = quote_spanned!(Span::mixed_site()=> marker);
+    let missing = quote_spanned!(Span::mixed_site().located_at(name.span())=> missing);
+    let literal = quote_spanned! {name.span()=>
+        #name {
+            #(#fields: ::html_codegen::props::LiteralValue::value(#marker, #bindings),)*
+            ..#missing
+        }
+    };
+    let built = quote_spanned!(name.span()=> built);
+    let build = quote_spanned!(name.span()=> ::html_codegen::props::Build::build(#built));
+    let setters_trait = quote_spanned!(Span::mixed_site()=> Setters);
+
+    quote! {
+        match (#(#values,)*) {
+            (#(#bindings,)*) => {
+                #[allow(unused_imports)]
+                use ::html_codegen::props::{ConstructBuilder as _, ConstructLiteral as _};
+
+                #[allow(dead_code)]
+                trait #setters_trait: ::html_codegen::props::Builder + Sized {
+                    #(#fallback_setters)*
+                }
+                impl<T: ::html_codegen::props::Builder> #setters_trait for T {}
+
+                let #probe = ::html_codegen::props::Probe::new(|#name { .. }| {});
+                match (&#probe).construct() {
+                    #[allow(unreachable_code, unused_variables, clippy::needless_update)]
+                    ::html_codegen::props::Construct::Literal(#marker) => {
+                        ::html_codegen::props::literal(#marker);
+                        let #missing;
+                        #probe.component(#literal)
+                    }
+                    #[allow(unreachable_code)]
+                    ::html_codegen::props::Construct::Builder(#builder) => {
+                        let #built = #builder() #(#setters)*;
+                        #build
+                    }
+                }
+            }
+        }
+    }
+}
+
 pub struct SimpleElementAttributes<'a> {
     attributes: &'a Attributes,
+    spreads: &'a [AttributeSpread],
 }
 
-impl<'a> ToTokens for SimpleElementAttributes<'a> {
+/// The attribute value of a literal, already escaped at compile time
+fn folded_literal(lit: &syn::Lit) -> Option<proc_macro2::TokenStream> {
+    let text = match lit {
+        syn::Lit::Str(text) => escape_html(&text.value()),
+        syn::Lit::Char(character) => escape_html(&character.value().to_string()),
+        syn::Lit::Int(number) => number.base10_digits().to_string(),
+        syn::Lit::Float(number) => number.base10_digits().to_string(),
+        syn::Lit::Bool(flag) if flag.value => return Some(quote!(::html_codegen::AV::Short)),
+        syn::Lit::Bool(_) => return Some(quote!(::html_codegen::AV::None)),
+        _ => return None,
+    };
+
+    Some(quote!(::html_codegen::AV::SomeRaw(::html_codegen::Raw(#text))))
+}
+
+/// Mirrors `html_codegen::html_escaping::escape_html`, for text known at
+/// compile time
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '>' => escaped.push_str("&gt;"),
+            '<' => escaped.push_str("&lt;"),
+            '"' => escaped.push_str("&quot;"),
+            '&' => escaped.push_str("&amp;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl SimpleElementAttributes<'_> {
+    /// Builds the value of the `directive` attribute out of the attribute
+    /// itself and all of its `directive:name={value}` directives
+    fn merged_directive_tokens(&self, directive: &str) -> proc_macro2::TokenStream {
+        let parts = self
+            .attributes
+            .iter()
+            .filter(|attribute| attribute.targets(directive))
+            .map(|attribute| {
+                let value = attribute.value_tokens();
+                let name = attribute_name(attribute.ident());
+
+                match (directive, attribute.directive().is_some()) {
+                    ("class", true) => quote! { .with((#name, #value)) },
+                    ("class", false) => quote! { .with(#value) },
+                    (_, true) => quote! { .with(#name, #value) },
+                    (_, false) => quote! { .merge(#value) },
+                }
+            });
+
+        match directive {
+            "class" => quote! { ::html_codegen::ClassList::new() #(#parts)* },
+            _ => quote! { ::html_codegen::Style::new() #(#parts)* },
+        }
+    }
+}
+
+impl ToTokens for SimpleElementAttributes<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        if self.attributes.is_empty() {
+        if self.attributes.is_empty() && self.spreads.is_empty() {
             quote!(None).to_tokens(tokens);
         } else {
+            let spreads_at = |position: usize| {
+                self.spreads
+                    .iter()
+                    .filter(move |spread| spread.position == position)
+                    .map(|AttributeSpread { value, .. }| {
+                        quote! {
+                            for (key, value) in #value {
+                                hm.insert(key, ::html_codegen::ToAttribute::from_value(value));
+                            }
+                        }
+                    })
+            };
+
+            // `class:` and `style:` directives are merged with the attribute
+            // they target into a single `ClassList` or `Style`, rendered where
+            // the first of them was written.
+            let merged_directives: Vec<&str> = DIRECTIVES
+                .iter()
+                .copied()
+                .filter(|directive| {
+                    self.attributes
+                        .iter()
+                        .any(|attribute| attribute.directive().is_some_and(|d| d == directive))
+                })
+                .collect();
+            let mut written_directives = vec![];
+
             let attrs: Vec<_> = self
                 .attributes
                 .iter()
-                .map(|attribute| {
-                    let mut iter = attribute.ident().iter();
-                    let first_word = iter.next().unwrap().unraw();
-                    let ident = iter.fold(first_word.to_string(), |acc, curr| {
-                        format!("{}-{}", acc, curr.unraw())
-                    });
+                .enumerate()
+                .map(|(position, attribute)| {
+                    let spreads = spreads_at(position);
+
+                    let merged_directive = merged_directives
+                        .iter()
+                        .find(|directive| attribute.targets(directive));
+
+                    if let Some(&directive) = merged_directive {
+                        if written_directives.contains(&directive) {
+                            return quote! { #(#spreads)* };
+                        }
+                        written_directives.push(directive);
+
+                        let value = self.merged_directive_tokens(directive);
+                        return quote! {
+                            #(#spreads)*
+                            hm.insert(#directive, ::html_codegen::ToAttribute::from_value(#value));
+                        };
+                    }
+
+                    let ident = attribute_name(attribute.ident());
+
+                    if let Some(value) = attribute.unbraced_literal().and_then(folded_literal) {
+                        return quote! {
+                            #(#spreads)*
+                            hm.insert(#ident, #value);
+                        };
+                    }
+
                     let value = attribute.value_tokens();
 
                     quote! {
+                        #(#spreads)*
                         hm.insert(#ident, ::html_codegen::ToAttribute::from_value(#value).into());
                     }
                 })
                 .collect();
+            let trailing_spreads = spreads_at(self.attributes.len());
 
             let hashmap_declaration = quote! {{
                 let mut hm = ::html_codegen::OrderedHashMap::<&str, ::html_codegen::AV<'_>>::new();
                 #(#attrs)*
+                #(#trailing_spreads)*
                 Some(hm)
             }};
 
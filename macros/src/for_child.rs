@@ -0,0 +1,61 @@
+use crate::children::Children;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+
+/// A `for item in items { ... }` child, or `for index, item in items { ... }`
+/// to also bind the position of each item.
+pub struct ForChild {
+    pub for_token: syn::Token![for],
+    pub index: Option<syn::Ident>,
+    pub pattern: syn::Pat,
+    pub items: syn::Expr,
+    pub body: Children,
+}
+
+impl Parse for ForChild {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let for_token = input.parse::<syn::Token![for]>()?;
+
+        let (index, pattern) = if input.peek(syn::Ident) && input.peek2(syn::Token![,]) {
+            let index = input.parse::<syn::Ident>()?;
+            input.parse::<syn::Token![,]>()?;
+            (Some(index), syn::Pat::parse_single(input)?)
+        } else {
+            (None, syn::Pat::parse_single(input)?)
+        };
+
+        input.parse::<syn::Token![in]>()?;
+        let items = syn::Expr::parse_without_eager_brace(input)?;
+        let body = Children::parse_braced(input)?;
+
+        Ok(Self {
+            for_token,
+            index,
+            pattern,
+            items,
+            body,
+        })
+    }
+}
+
+impl ToTokens for ForChild {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let pattern = &self.pattern;
+        let items = &self.items;
+        let body = self.body.as_tuple_tokens();
+
+        let each = match &self.index {
+            None => quote! {
+                ::html_codegen::Each::new(#items, |#pattern| #body)
+            },
+            Some(index) => quote! {
+                ::html_codegen::Each::new(
+                    ::core::iter::Iterator::enumerate(::core::iter::IntoIterator::into_iter(#items)),
+                    |(#index, #pattern)| #body,
+                )
+            },
+        };
+
+        each.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,157 @@
+use crate::element_attributes::escape_html;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+
+/// An `f "Hello {name}"` text child, which renders the text around its
+/// `{expr}` interpolations like a text child, and each interpolation
+/// through `Render`.
+pub struct FormatChild {
+    pub segments: Vec<Segment>,
+}
+
+pub enum Segment {
+    Text(String),
+    Value(syn::Expr),
+}
+
+impl FormatChild {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(syn::Ident)
+            && input.peek2(syn::LitStr)
+            && input
+                .fork()
+                .parse::<syn::Ident>()
+                .is_ok_and(|ident| ident == "f")
+    }
+}
+
+impl Parse for FormatChild {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<syn::Ident>()?;
+        let lit = input.parse::<syn::LitStr>()?;
+        let segments = parse_segments(&lit.value(), lit.span())?;
+
+        Ok(Self { segments })
+    }
+}
+
+/// Splits `Hello {name}!` into text and value segments, where `{{` and `}}`
+/// are escaped braces like in `format!`.
+fn parse_segments(template: &str, span: Span) -> Result<Vec<Segment>> {
+    let mut segments = vec![];
+    let mut text = String::new();
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut source = String::new();
+                let mut depth = 0;
+                loop {
+                    match chars.next() {
+                        Some('}') if depth == 0 => break,
+                        Some(c) => {
+                            match c {
+                                '{' => depth += 1,
+                                '}' => depth -= 1,
+                                _ => {}
+                            }
+                            source.push(c);
+                        }
+                        None => return Err(syn::Error::new(span, "Unclosed `{` in text")),
+                    }
+                }
+
+                if !text.is_empty() {
+                    segments.push(Segment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(Segment::Value(parse_interpolation(&source, span)?));
+            }
+            '}' => {
+                return Err(syn::Error::new(
+                    span,
+                    "Unmatched `}` in text, use `}}` to render a brace",
+                ))
+            }
+            c => text.push(c),
+        }
+    }
+
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+
+    Ok(segments)
+}
+
+fn parse_interpolation(source: &str, span: Span) -> Result<syn::Expr> {
+    let tokens: TokenStream = source
+        .parse()
+        .map_err(|_| syn::Error::new(span, format!("Invalid expression `{}` in text", source)))?;
+
+    syn::parse2(respan(tokens, span))
+}
+
+/// Points the tokens of an interpolation at the text literal they came from
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            if let TokenTree::Group(group) = &token {
+                let mut respanned =
+                    proc_macro2::Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                token = TokenTree::Group(respanned);
+            } else {
+                token.set_span(span);
+            }
+            token
+        })
+        .collect()
+}
+
+impl FormatChild {
+    /// The renderable of the text, left unescaped for `<script>` and
+    /// `<style>`, which neutralize it themselves
+    pub fn to_raw_text_tokens(&self) -> TokenStream {
+        self.renderable_tokens(|text| quote! { #text })
+    }
+
+    fn renderable_tokens(&self, text_tokens: impl Fn(&str) -> TokenStream) -> TokenStream {
+        let segments: Vec<_> = self
+            .segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Text(text) => text_tokens(text),
+                Segment::Value(value) => quote! { #value },
+            })
+            .collect();
+
+        match segments.as_slice() {
+            [] => quote! { () },
+            [single] => quote! { #single },
+            [first, rest @ ..] => rest.iter().fold(quote! { #first }, |renderable, current| {
+                quote! { (#renderable, #current) }
+            }),
+        }
+    }
+}
+
+impl ToTokens for FormatChild {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.renderable_tokens(|text| {
+            let escaped = escape_html(text);
+            quote! { ::html_codegen::Raw(#escaped) }
+        })
+        .to_tokens(tokens);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::props::{create_props_builder, without_prop_attributes, Prop};
 use proc_macro::TokenStream;
 use proc_macro_error::emit_error;
 use quote::quote;
@@ -9,29 +10,45 @@ pub fn create_function_component(f: syn::ItemFn) -> TokenStream {
     let inputs = f.sig.inputs;
     let block = f.block;
     let vis = f.vis;
+    let name = struct_name.to_string();
 
-    let inputs_block = if inputs.len() > 0 {
-        let input_names: Vec<_> = inputs.iter().collect();
+    let typed_inputs: Vec<_> = inputs
+        .iter()
+        .filter_map(|argument| match argument {
+            syn::FnArg::Typed(typed) => Some(typed),
+            syn::FnArg::Receiver(rec) => {
+                emit_error!(rec.span(), "Don't use `self` on components");
+                None
+            }
+        })
+        .filter_map(|typed| match &*typed.pat {
+            syn::Pat::Ident(pat_ident) => Some((typed, &pat_ident.ident)),
+            pat => {
+                emit_error!(pat.span(), "Component props must be named");
+                None
+            }
+        })
+        .collect();
+
+    let inputs_block = if !inputs.is_empty() {
+        let fields = typed_inputs.iter().map(|(typed, ident)| {
+            let attrs = without_prop_attributes(&typed.attrs);
+            let ty = &typed.ty;
+            quote!(#(#attrs)* #vis #ident: #ty)
+        });
 
-        quote!({ #(#vis #input_names),* })
+        quote!({ #(#fields),* })
     } else {
         quote!(;)
     };
 
-    let inputs_reading = if inputs.len() == 0 {
+    let inputs_reading = if inputs.is_empty() {
         quote!()
     } else {
-        let input_names: Vec<_> = inputs
+        let input_names: Vec<_> = typed_inputs
             .iter()
-            .filter_map(|argument| match argument {
-                syn::FnArg::Typed(typed) => Some(typed),
-                syn::FnArg::Receiver(rec) => {
-                    emit_error!(rec.span(), "Don't use `self` on components");
-                    None
-                }
-            })
-            .map(|value| {
-                let pat = &value.pat;
+            .map(|(typed, _)| {
+                let pat = &typed.pat;
                 quote!(#pat)
             })
             .collect();
@@ -40,18 +57,181 @@ pub fn create_function_component(f: syn::ItemFn) -> TokenStream {
         )
     };
 
+    let props: Vec<_> = typed_inputs
+        .iter()
+        .map(|(typed, ident)| Prop::new((*ident).clone(), (*typed.ty).clone(), &typed.attrs))
+        .collect();
+    let props_builder = create_props_builder(
+        &vis,
+        &struct_name,
+        &f.sig.generics,
+        (!inputs.is_empty()).then_some(props.as_slice()),
+    );
+
+    // Components that return a `Result` can fail, so their body runs in a
+    // closure that `?` can return from. Other return types are only checked,
+    // as `impl Trait` can't be written on a `let`.
+    let rendered = match &f.sig.output {
+        syn::ReturnType::Type(_, ty) if is_result(ty) => quote! {
+            #inputs_reading
+            let result: #ty = (|| -> #ty #block)();
+            match result {
+                ::core::result::Result::Ok(result) => ::html_codegen::Render::render_into(result, w),
+                ::core::result::Result::Err(error) => {
+                    ::core::result::Result::Err(::html_codegen::RenderError::component(#name, error).raise())
+                }
+            }
+        },
+        syn::ReturnType::Type(_, ty) if !matches!(**ty, syn::Type::ImplTrait(_)) => quote! {
+            let result: #ty = {
+                #inputs_reading
+                #block
+            };
+            ::html_codegen::Render::render_into(result, w)
+        },
+        _ => quote! {
+            let result = {
+                #inputs_reading
+                #block
+            };
+            ::html_codegen::Render::render_into(result, w)
+        },
+    };
+
+    // The body only runs when the component renders, so its hint is the
+    // hints of the props it can render
+    let render_params = render_type_params(&f.sig.generics);
+    let renderable_props = typed_inputs
+        .iter()
+        .filter_map(|(typed, ident)| match &*typed.ty {
+            syn::Type::Path(path) if path.qself.is_none() => path
+                .path
+                .get_ident()
+                .filter(|ty| render_params.contains(ty))
+                .map(|_| ident),
+            _ => None,
+        });
+    let size_hints: Vec<_> = renderable_props
+        .map(|prop| quote!(::html_codegen::Render::size_hint(&self.#prop)))
+        .collect();
+    let size_hint = if size_hints.is_empty() {
+        quote!(0)
+    } else {
+        quote!(#(#size_hints)+*)
+    };
+
+    // Rendering by reference renders a clone of the props, so it's only
+    // implemented when they're `Clone`. The bounds are higher-ranked so
+    // that props that aren't `Clone` leave the impl unusable rather than
+    // failing to compile, and are on `CloneProp` so that they don't make
+    // `Clone` ambiguous for props with lifetimes.
+    let mut ref_generics = f.sig.generics.clone();
+    let ref_where_clause = ref_generics.make_where_clause();
+    let mut cloned_types = Vec::new();
+    for (typed, _) in &typed_inputs {
+        let mut ty = (*typed.ty).clone();
+        syn::visit_mut::VisitMut::visit_type_mut(&mut HigherRankedLifetimes, &mut ty);
+
+        let name = quote!(#ty).to_string();
+        if !cloned_types.contains(&name) {
+            cloned_types.push(name);
+            ref_where_clause.predicates.push(syn::parse_quote!(
+                for<'__render_ref> #ty: ::html_codegen::props::CloneProp
+            ));
+        }
+    }
+    let ref_where_clause = &ref_generics.where_clause;
+    let cloned_props = if inputs.is_empty() {
+        quote!(Self)
+    } else {
+        let fields = typed_inputs.iter().map(
+            |(_, ident)| quote!(#ident: ::html_codegen::props::CloneProp::clone_prop(&self.#ident)),
+        );
+        quote!(Self { #(#fields),* })
+    };
+
     TokenStream::from(quote! {
         #[derive(Debug)]
         #vis struct #struct_name #impl_generics #inputs_block
 
+        #props_builder
+
         impl #impl_generics ::html_codegen::Render for #struct_name #ty_generics #where_clause {
             fn render_into<W: std::fmt::Write>(self, w: &mut W) -> std::fmt::Result {
-                let result = {
-                    #inputs_reading
-                    #block
-                };
-                ::html_codegen::Render::render_into(result, w)
+                #rendered
+            }
+
+            fn size_hint(&self) -> usize {
+                #size_hint
+            }
+        }
+
+        impl #impl_generics ::html_codegen::RenderRef for #struct_name #ty_generics #ref_where_clause {
+            fn render_ref<W: std::fmt::Write>(&self, w: &mut W) -> std::fmt::Result {
+                ::html_codegen::Render::render_into(#cloned_props, w)
+            }
+
+            fn size_hint_ref(&self) -> usize {
+                ::html_codegen::Render::size_hint(self)
             }
         }
     })
 }
+
+/// The type parameters bound by `Render`, like `Children` in
+/// `<Children: Render>` or `where Children: Render`
+fn render_type_params(generics: &syn::Generics) -> Vec<&syn::Ident> {
+    fn is_render(bound: &syn::TypeParamBound) -> bool {
+        match bound {
+            syn::TypeParamBound::Trait(bound) => bound
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Render"),
+            _ => false,
+        }
+    }
+
+    let where_bounds = generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates)
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(predicate) => Some(predicate),
+            _ => None,
+        })
+        .filter(|predicate| predicate.bounds.iter().any(is_render))
+        .filter_map(|predicate| match &predicate.bounded_ty {
+            syn::Type::Path(path) => path.path.get_ident(),
+            _ => None,
+        });
+
+    generics
+        .type_params()
+        .filter(|param| param.bounds.iter().any(is_render))
+        .map(|param| &param.ident)
+        .chain(where_bounds)
+        .collect()
+}
+
+/// Replaces the lifetimes of a prop's type with the higher-ranked one of
+/// its `CloneProp` bound, as props with the same type but different
+/// lifetimes would make their bounds ambiguous
+struct HigherRankedLifetimes;
+
+impl syn::visit_mut::VisitMut for HigherRankedLifetimes {
+    fn visit_lifetime_mut(&mut self, lifetime: &mut syn::Lifetime) {
+        *lifetime = syn::Lifetime::new("'__render_ref", lifetime.span());
+    }
+}
+
+/// Whether a return type is a `Result`, by the last segment of its path
+fn is_result(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Result"),
+        _ => false,
+    }
+}
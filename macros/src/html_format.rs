@@ -0,0 +1,32 @@
+use crate::element::Element;
+use syn::parse::{Parse, ParseStream, Result};
+
+/// The input of `html_format!` and `html_format_args!`: an element, after
+/// options like `#![minify]`
+pub struct HtmlFormat {
+    pub minify: bool,
+    pub element: Element,
+}
+
+impl Parse for HtmlFormat {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut minify = false;
+
+        for attribute in input.call(syn::Attribute::parse_inner)? {
+            if attribute.path().is_ident("minify") {
+                attribute.meta.require_path_only()?;
+                minify = true;
+            } else {
+                return Err(syn::Error::new_spanned(
+                    attribute,
+                    "Unknown option, expected `#![minify]`",
+                ));
+            }
+        }
+
+        Ok(Self {
+            minify,
+            element: input.parse()?,
+        })
+    }
+}
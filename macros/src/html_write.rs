@@ -0,0 +1,20 @@
+use crate::element::Element;
+use syn::parse::{Parse, ParseStream, Result};
+
+/// The input of `html_write!`: the writer, and the element to render into it
+pub struct HtmlWrite {
+    pub writer: syn::Expr,
+    pub element: Element,
+}
+
+impl Parse for HtmlWrite {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let writer = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+
+        Ok(Self {
+            writer,
+            element: input.parse()?,
+        })
+    }
+}
@@ -0,0 +1,63 @@
+use crate::children::Children;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+
+/// An `if cond { ... } else if cond { ... } else { ... }` child,
+/// where `cond` may also be an `if let` pattern.
+pub struct IfChild {
+    pub if_token: syn::Token![if],
+    pub condition: syn::Expr,
+    pub then_branch: Children,
+    pub else_branch: Option<ElseBranch>,
+}
+
+pub enum ElseBranch {
+    If(Box<IfChild>),
+    Else(Children),
+}
+
+impl Parse for IfChild {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let if_token = input.parse::<syn::Token![if]>()?;
+        let condition = syn::Expr::parse_without_eager_brace(input)?;
+        let then_branch = Children::parse_braced(input)?;
+
+        let else_branch = if input.parse::<Option<syn::Token![else]>>()?.is_some() {
+            if input.peek(syn::Token![if]) {
+                Some(ElseBranch::If(Box::new(input.parse::<IfChild>()?)))
+            } else {
+                Some(ElseBranch::Else(Children::parse_braced(input)?))
+            }
+        } else {
+            None
+        };
+
+        Ok(Self {
+            if_token,
+            condition,
+            then_branch,
+            else_branch,
+        })
+    }
+}
+
+impl ToTokens for IfChild {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let condition = &self.condition;
+        let then_branch = self.then_branch.as_tuple_tokens();
+        let else_branch = match &self.else_branch {
+            None => quote! { () },
+            Some(ElseBranch::If(if_child)) => quote! { #if_child },
+            Some(ElseBranch::Else(children)) => children.as_tuple_tokens(),
+        };
+
+        quote! {
+            if #condition {
+                ::html_codegen::Either::Left(#then_branch)
+            } else {
+                ::html_codegen::Either::Right(#else_branch)
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -2,13 +2,27 @@ extern crate proc_macro;
 
 mod child;
 mod children;
+mod comment_child;
 mod element;
 mod element_attribute;
 mod element_attributes;
+mod for_child;
+mod format_child;
 mod function_component;
+mod html_format;
+mod html_write;
+mod if_child;
+mod match_child;
+mod minify;
+mod props;
+mod slot;
+#[cfg_attr(not(feature = "strict-html"), allow(dead_code))]
+mod strict_html;
 mod tags;
 
 use element::Element;
+use html_format::HtmlFormat;
+use html_write::HtmlWrite;
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 use quote::quote;
@@ -48,7 +62,7 @@ use syn::parse_macro_input;
 /// assert_eq!(rendered, r#"<h1>Hello world!</h1>"#);
 /// ```
 ///
-/// ### Values are always surrounded by curly braces
+/// ### Values are surrounded by curly braces
 ///
 /// ```rust
 /// # use html_codegen_macros::html;
@@ -60,6 +74,20 @@ use syn::parse_macro_input;
 /// assert_eq!(rendered, r#"<div id="main"></div>"#);
 /// ```
 ///
+/// ### Literal values don't need curly braces
+/// String, number and boolean literals are rendered at compile time.
+/// `true` renders the attribute without a value, and `false` omits it.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let rendered = html! {
+///     <input id="main" tabindex=3 hidden=true disabled=false />
+/// }.unwrap();
+///
+/// assert_eq!(rendered, r#"<input id="main" tabindex="3" hidden/>"#);
+/// ```
+///
 /// ### HTML entities can accept dashed-separated value
 ///
 /// ```rust
@@ -109,11 +137,358 @@ use syn::parse_macro_input;
 ///
 /// assert_eq!(rendered, r#"<div class="some_class"></div>"#);
 /// ```
+///
+/// ### Namespaced and prefixed attributes
+/// Attribute names can also be separated by `:`, `::` and `.`, and start with
+/// `@` or `:`, which covers XML namespaces and the attributes of htmx and
+/// Alpine. They are rendered as written. `class:` and `style:` are still
+/// directives.
+///
+/// A `:`-prefixed attribute must not follow a punned attribute: tokens don't
+/// keep their whitespace, so `<input disabled :class="x">` is read as a
+/// single `disabled:class` attribute. It has to come first, or the punned
+/// attribute needs a value.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// # // strict-html only allows these through `HTML_CODEGEN_EXTRA_ATTRIBUTES`
+/// # #[cfg(feature = "strict-html")] fn main() {}
+/// # #[cfg(not(feature = "strict-html"))] fn main() {
+/// let rendered = html! {
+///     <button :class="{ active }" @click="open = true" x-on:keyup.enter.prevent="submit()" />
+/// }.unwrap();
+///
+/// assert_eq!(
+///     rendered,
+///     r#"<button :class="{ active }" @click="open = true" x-on:keyup.enter.prevent="submit()"></button>"#
+/// );
+/// # }
+/// ```
+///
+/// ### Text children
+/// String literals can be written directly as children, and `f "..."`
+/// interpolates `{expr}`s like `format!`, rendering each of them through
+/// [`Render`](../html_codegen/trait.Render.html) so they are escaped.
+/// Use `{{` and `}}` for literal braces. The `f` prefix needs a space, as
+/// `f"..."` is a reserved token since the 2021 edition.
+///
+/// Text is rendered exactly as written, and whitespace between children
+/// in the macro is never rendered, so spaces must be part of the text.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let name = "<Gal>";
+/// let count = 3;
+///
+/// let rendered = html! {
+///     <p>
+///         "Hello, " <b>{name}</b>
+///         f " - you have {count} messages"
+///     </p>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, "<p>Hello, <b>&lt;Gal&gt;</b> - you have 3 messages</p>");
+/// ```
+///
+/// ### Comments
+/// `<!-- "text" -->` renders an HTML comment, and `<!-- {expr} -->` takes
+/// any `AsRef<str>`. The text is rendered as written, without adding spaces
+/// around it. Literal text is written as it is, so it can hold conditional
+/// comments, while the text of an expression is escaped so it can't end the
+/// comment.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let version = "1.2.0";
+///
+/// let rendered = html! {
+///     <head>
+///         <!-- " Generated by build " -->
+///         <!-- "[if IE]><script src=\"ie.js\"></script><![endif]" -->
+///         <!-- {format!("v{version} -->")} -->
+///     </head>
+/// }.unwrap();
+///
+/// assert_eq!(
+///     rendered,
+///     r#"<head><!-- Generated by build --><!--[if IE]><script src="ie.js"></script><![endif]--><!--v1.2.0 - ->--></head>"#
+/// );
+/// ```
+///
+/// ### Script and style contents
+/// The children of `<script>` and `<style>` are raw text rather than HTML,
+/// so strings are rendered without escaping, and any `</script` or
+/// `</style` in them is rewritten to `<\/script` or `<\/style` so it
+/// can't close the element. `html_format!` does the same.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let selector = "nav > a";
+///
+/// let rendered = html! {
+///     <style>{selector}" { color: red }"</style>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, "<style>nav > a { color: red }</style>");
+/// ```
+///
+/// ### `if` / `else` children
+/// Branches can render different element or component types, and may
+/// contain any number of children. A missing `else` renders nothing.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let unread = Some(3);
+///
+/// let rendered = html! {
+///     <span>
+///         if let Some(count) = unread {
+///             <b>{count}</b>{" unread"}
+///         } else {
+///             {"All caught up"}
+///         }
+///     </span>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, "<span><b>3</b> unread</span>");
+/// ```
+///
+/// ### `for` children
+/// Any `IntoIterator` is rendered item by item, without collecting it.
+/// Use `for index, item in items` to also bind the item's position.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let steps = vec!["Sign up", "Confirm email"];
+///
+/// let rendered = html! {
+///     <ul>
+///         for index, step in &steps {
+///             <li id={format!("step-{index}")}>{*step}</li>
+///         }
+///     </ul>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, r#"<ul><li id="step-0">Sign up</li><li id="step-1">Confirm email</li></ul>"#);
+/// ```
+///
+/// ### `match` children
+/// Each arm is either a single child or a braced list of children, and
+/// can render a different element or component type.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// enum Status { Shipped { days: u8 }, Cancelled }
+///
+/// let status = Status::Shipped { days: 2 };
+///
+/// let rendered = html! {
+///     <p>
+///         match status {
+///             Status::Shipped { days } if days > 1 => {
+///                 {"Arriving in "}<b>{days}</b>{" days"}
+///             }
+///             Status::Shipped { .. } => <b>{"Arriving tomorrow"}</b>,
+///             Status::Cancelled => <i>{"Cancelled"}</i>,
+///         }
+///     </p>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, "<p>Arriving in <b>2</b> days</p>");
+/// ```
+///
+/// ### Class directives
+/// `class:name={condition}` adds `name` to the element's classes only when
+/// `condition` is `true`, and `class:name` is punned to `class:name={name}`.
+/// They are merged with the `class` attribute into a single, deduplicated
+/// [`ClassList`](../html_codegen/struct.ClassList.html).
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let active = true;
+/// let is_disabled = false;
+///
+/// let rendered = html! {
+///     <a class={"nav-link"} class:active class:disabled={is_disabled}>{"Home"}</a>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, r#"<a class="nav-link active">Home</a>"#);
+/// ```
+///
+/// ### Style directives
+/// `style:property={value}` sets a single CSS declaration, and is skipped
+/// when `value` is `None`. They are merged with the `style` attribute into a
+/// single [`Style`](../html_codegen/struct.Style.html), which escapes values
+/// so they can't inject other declarations.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let color = "tomato";
+/// let width: Option<&str> = None;
+///
+/// let rendered = html! {
+///     <p style={"margin: 0"} style:color style:max-width={width}>{"Hi"}</p>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, r#"<p style="margin: 0; color: tomato">Hi</p>"#);
+/// ```
+///
+/// ### Named slots
+/// `<slot:name>...</slot:name>` children of a custom component are passed
+/// as its `name` field, the same way other children are passed as its
+/// `children` field. Filling a slot the component doesn't have is a
+/// compile error.
+///
+/// ```rust
+/// # use html_codegen_macros::{component, html, rsx};
+/// # use pretty_assertions::assert_eq;
+/// use html_codegen::Render;
+///
+/// #[component]
+/// fn Card<Header: Render, Children: Render>(header: Header, children: Children) {
+///     rsx! {
+///         <section>
+///             <header>{header}</header>
+///             {children}
+///         </section>
+///     }
+/// }
+///
+/// let rendered = html! {
+///     <Card>
+///         <slot:header><h2>"Title"</h2></slot:header>
+///         <p>"Body"</p>
+///     </Card>
+/// }.unwrap();
+///
+/// assert_eq!(rendered, "<section><header><h2>Title</h2></header><p>Body</p></section>");
+/// ```
+///
+/// ### Attribute spreads
+/// `{..attrs}` inserts every `(key, value)` pair of an iterator, where each
+/// value implements `ToAttribute`. Attributes are applied left to right, so
+/// the last one to set a key wins and is rendered in its position.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// # use pretty_assertions::assert_eq;
+/// let extra_attrs = [("name", "email"), ("type", "email")];
+///
+/// let rendered = html! {
+///     <input {..extra_attrs} type={"text"} />
+/// }.unwrap();
+///
+/// assert_eq!(rendered, r#"<input name="email" type="text"/>"#);
+/// ```
+///
+/// Custom components accept a single `{..props}`, which fills the fields that
+/// are not set explicitly, like Rust's struct update syntax.
+///
+/// ```rust
+/// # use html_codegen_macros::{component, html, rsx};
+/// # use pretty_assertions::assert_eq;
+/// #[component]
+/// fn Greeting<'a>(greeting: &'a str, name: &'a str) {
+///     rsx! { <p>{greeting}{", "}{name}</p> }
+/// }
+///
+/// let defaults = Greeting { greeting: "Hello", name: "stranger" };
+///
+/// let rendered = html! { <Greeting name={"Gal"} {..defaults} /> }.unwrap();
+///
+/// assert_eq!(rendered, "<p>Hello, Gal</p>");
+/// ```
+///
+/// ### Strict HTML
+/// With the `strict-html` feature, lowercase tags and their attributes are
+/// checked against the HTML Living Standard, so a typo like `<dvi>` or
+/// `clas="..."` is a compile error that suggests the closest known name.
+/// `data-*` and `aria-*` attributes are always allowed, and the children of
+/// `<svg>`, `<math>` and `<Xml>` aren't checked.
+///
+/// Other attributes, like the ones of htmx or Alpine, can be allowed through
+/// the `HTML_CODEGEN_EXTRA_ATTRIBUTES` environment variable, a comma separated
+/// list where a trailing `*` matches a prefix. It can be set for a whole
+/// project in `.cargo/config.toml`:
+///
+/// ```toml
+/// [env]
+/// HTML_CODEGEN_EXTRA_ATTRIBUTES = "hx-*, x-*, @*, :*, up-target"
+/// ```
+///
+/// The macros make the crates that use them depend on the variable, so Cargo
+/// rebuilds them when it changes. It applies to every crate of the build.
+///
+/// Enumerated attributes, like the `type` of an `<input>` or the `target` of
+/// an `<a>`, only accept literals that are one of their keywords, or values of
+/// the matching `html_codegen::enumerated` enum. Other values can be passed
+/// through `html_codegen::enumerated::Unchecked`.
+///
+/// ```rust
+/// # use html_codegen_macros::html;
+/// use html_codegen::enumerated::{InputType, Unchecked};
+///
+/// let kind = InputType::Email;
+/// let frame = "preview";
+///
+/// html! {
+///     <form method="post" target={Unchecked(frame)}>
+///         <input type={kind} autocomplete="shipping postal-code" />
+///     </form>
+/// };
+/// ```
 #[proc_macro]
 #[proc_macro_error]
 pub fn html(input: TokenStream) -> TokenStream {
-    let el = proc_macro2::TokenStream::from(rsx(input));
+    let el = parse_macro_input!(input as Element);
+    #[cfg(feature = "strict-html")]
+    let el = match strict_html::validate(el) {
+        Ok(el) => el,
+        Err(errors) => return errors.into(),
+    };
     let result = quote! { ::html_codegen::Render::render(#el) };
+    #[cfg(feature = "strict-html")]
+    let result = strict_html::track_extra_attributes(result);
+    TokenStream::from(result)
+}
+
+/// Render an HTML element into an [`io::Write`](std::io::Write), like a
+/// file or a socket, returning an [`io::Result`](std::io::Result)
+///
+/// This is [`html!`] for `Render::render_to_io`: the error of the writer is
+/// returned as it is, and the error of a component as an
+/// [`io::ErrorKind::Other`](std::io::ErrorKind::Other) error.
+///
+/// ```rust
+/// # use html_codegen_macros::html_write;
+/// # use pretty_assertions::assert_eq;
+/// let mut output = Vec::new();
+///
+/// html_write!(&mut output, <p class="greeting">{"Hello"}</p>).unwrap();
+///
+/// assert_eq!(output, br#"<p class="greeting">Hello</p>"#);
+/// ```
+#[proc_macro]
+#[proc_macro_error]
+pub fn html_write(input: TokenStream) -> TokenStream {
+    let HtmlWrite { writer, element } = parse_macro_input!(input as HtmlWrite);
+    #[cfg(feature = "strict-html")]
+    let element = match strict_html::validate(element) {
+        Ok(element) => element,
+        Err(errors) => return errors.into(),
+    };
+    let result = quote! { ::html_codegen::Render::render_to_io(#element, #writer) };
+    #[cfg(feature = "strict-html")]
+    let result = strict_html::track_extra_attributes(result);
     TokenStream::from(result)
 }
 
@@ -122,7 +497,14 @@ pub fn html(input: TokenStream) -> TokenStream {
 #[proc_macro_error]
 pub fn rsx(input: TokenStream) -> TokenStream {
     let el = parse_macro_input!(input as Element);
+    #[cfg(feature = "strict-html")]
+    let el = match strict_html::validate(el) {
+        Ok(el) => el,
+        Err(errors) => return errors.into(),
+    };
     let result = quote! { #el };
+    #[cfg(feature = "strict-html")]
+    let result = strict_html::track_extra_attributes(result);
     TokenStream::from(result)
 }
 
@@ -164,14 +546,120 @@ pub fn rsx(input: TokenStream) -> TokenStream {
 /// #
 /// # let from_fn = html! {
 /// #     <UserFn name={String::from("Schniz")} />
-/// # };
+/// # }.unwrap();
 /// #
 /// # let from_struct = html! {
 /// #     <User name={String::from("Schniz")} />
-/// # };
+/// # }.unwrap();
 /// #
 /// # assert_eq!(from_fn, from_struct);
 /// ```
+///
+/// ### Optional props
+/// Props marked `#[prop(default)]` fall back to [`Default`] when omitted, and
+/// `#[prop(default = expr)]` to `expr`. `Option` props can always be omitted,
+/// and default to `None`. Leaving out any other prop is a compile error.
+///
+/// ```rust
+/// # use html_codegen_macros::{component, html, rsx};
+/// # use pretty_assertions::assert_eq;
+/// use html_codegen::enumerated::InputType;
+///
+/// #[component]
+/// fn Input<'a>(
+///     name: &'a str,
+///     #[prop(default = InputType::Text)] kind: InputType,
+///     #[prop(default)] required: bool,
+///     placeholder: Option<&'a str>,
+/// ) {
+///     rsx! { <input type={kind} name={name} required={required} placeholder={placeholder} /> }
+/// }
+///
+/// assert_eq!(
+///     html! { <Input name="email" /> }.unwrap(),
+///     r#"<input type="text" name="email"/>"#
+/// );
+/// assert_eq!(
+///     html! { <Input name="age" kind={InputType::Number} required=true placeholder={Some("42")} /> }.unwrap(),
+///     r#"<input type="number" name="age" required placeholder="42"/>"#
+/// );
+/// ```
+///
+/// ### Converted props
+/// Props marked `#[prop(into)]` accept anything that converts [`Into`] their
+/// type, so call sites don't need to convert values themselves. Options can
+/// be combined, like `#[prop(into, default)]`. The conversion happens in the
+/// props builder, so it doesn't apply to `{..props}` spreads.
+///
+/// ```rust
+/// # use html_codegen_macros::{component, html, rsx};
+/// # use pretty_assertions::assert_eq;
+/// #[component]
+/// fn Heading(#[prop(into)] title: String, #[prop(into, default = "title".into())] id: String) {
+///     rsx! { <h1 id={id}>{title}</h1> }
+/// }
+///
+/// let title = String::from("Owned");
+///
+/// assert_eq!(
+///     html! { <Heading title="Borrowed" /> }.unwrap(),
+///     r#"<h1 id="title">Borrowed</h1>"#
+/// );
+/// assert_eq!(
+///     html! { <Heading title={title} id="main" /> }.unwrap(),
+///     r#"<h1 id="main">Owned</h1>"#
+/// );
+/// ```
+///
+/// ### Fallible components
+/// A component that returns `Result<_, E>` can use `?`, and fails the render
+/// when it returns an error. `E` can be any error that converts into
+/// `Box<dyn Error + Send + Sync>`, and rendering returns it as a
+/// `RenderError` that carries the component's name. The return type counts
+/// as a `Result` when the last segment of its path is `Result`, so aliases
+/// like `io::Result<_>` work, and any other return type is left to the body.
+///
+/// ```rust
+/// # use html_codegen_macros::{component, html, rsx};
+/// # use pretty_assertions::assert_eq;
+/// # use std::collections::HashMap;
+/// #[component]
+/// fn Username<'a>(users: &'a HashMap<u32, &'a str>, id: u32) -> Result<_, String> {
+///     let name = users.get(&id).ok_or(format!("no user {id}"))?;
+///     Ok(rsx! { <b>{*name}</b> })
+/// }
+///
+/// let users = HashMap::from([(1, "Gal")]);
+/// let error = html! { <Username users={&users} id=2 /> }.unwrap_err();
+///
+/// assert_eq!(html! { <Username users={&users} id=1 /> }.unwrap(), "<b>Gal</b>");
+/// assert_eq!(error.to_string(), "failed to render `Username`: no user 2");
+/// ```
+///
+/// ### Size hints
+/// A component's body only runs when it renders, so its
+/// `Render::size_hint` is the sum of the hints of its props whose type is
+/// a type parameter bound by `Render`, like `children: Children` with
+/// `Children: Render`. Other components hint 0.
+///
+/// ### Rendering by reference
+/// When every prop is `Clone`, the component implements `RenderRef` by
+/// rendering a clone of its props, so a built component can be rendered
+/// more than once through a reference. Components with other props don't.
+///
+/// ```rust
+/// # use html_codegen_macros::{component, html, rsx};
+/// # use pretty_assertions::assert_eq;
+/// #[component]
+/// fn Footer<'a>(author: &'a str) {
+///     rsx! { <footer>{author}</footer> }
+/// }
+///
+/// let footer = rsx! { <Footer author="Jen" /> };
+///
+/// assert_eq!(html! { <main>{&footer}</main> }.unwrap(), "<main><footer>Jen</footer></main>");
+/// assert_eq!(html! { <aside>{&footer}</aside> }.unwrap(), "<aside><footer>Jen</footer></aside>");
+/// ```
 #[proc_macro_attribute]
 #[proc_macro_error]
 pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -179,21 +667,103 @@ pub fn component(_attr: TokenStream, item: TokenStream) -> TokenStream {
     function_component::create_function_component(f)
 }
 
+/// Generates the props builder that `rsx!` uses to create a custom component
+/// struct, for structs that implement [`Render`](../render/trait.Render.html)
+/// by hand, and implements `html_codegen::props::Props` for the struct.
+/// `#[component]` generates both on its own. Structs without them are
+/// created with a struct literal, which needs every field, even when they
+/// have a `builder()` of their own.
+///
+/// Fields can be made optional with `#[prop(default)]`, which falls back to
+/// [`Default`], or `#[prop(default = expr)]`. `Option` fields are optional
+/// and default to `None`. `#[prop(into)]` fields accept any value that
+/// converts [`Into`] their type.
+///
+/// ```rust
+/// # use html_codegen_macros::{html, rsx, Props};
+/// # use pretty_assertions::assert_eq;
+/// use html_codegen::Render;
+///
+/// #[derive(Props)]
+/// struct Avatar<'a> {
+///     src: &'a str,
+///     #[prop(default = 32)]
+///     size: u32,
+/// }
+///
+/// impl Render for Avatar<'_> {
+///     fn render_into<W: std::fmt::Write>(self, writer: &mut W) -> std::fmt::Result {
+///         Render::render_into(rsx! { <img src={self.src} width={self.size} /> }, writer)
+///     }
+/// }
+///
+/// let rendered = html! { <Avatar src="/me.png" /> }.unwrap();
+///
+/// assert_eq!(rendered, r#"<img src="/me.png" width="32"/>"#);
+/// ```
+#[proc_macro_derive(Props, attributes(prop))]
+#[proc_macro_error]
+pub fn derive_props(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    TokenStream::from(props::derive_props(input))
+}
+
+/// Like [`html_format!`], returning the [`format_args!`] instead of a `String`
 #[proc_macro]
 #[proc_macro_error]
 pub fn html_format_args(input: TokenStream) -> TokenStream {
-    let element = parse_macro_input!(input as Element);
+    let HtmlFormat { minify, element } = parse_macro_input!(input as HtmlFormat);
+    #[cfg(feature = "strict-html")]
+    let element = match strict_html::validate(element) {
+        Ok(element) => element,
+        Err(errors) => return errors.into(),
+    };
 
-    TokenStream::from(element.into_minimized_formatter())
+    let result = element.to_minimized_formatter(minify);
+    #[cfg(feature = "strict-html")]
+    let result = strict_html::track_extra_attributes(result);
+    TokenStream::from(result)
 }
 
+/// Formats an element into a `String` at compile time where it can, with
+/// values formatted with [`Display`](std::fmt::Display) and not escaped
+///
+/// Text children, like `"a < b"` and the text of `f "..."`, and unbraced
+/// literal attribute values are escaped like in `html!`.
+///
+/// ### Minified output
+/// `#![minify]` minifies the static text of the element like
+/// `Render::render_minified`. The values are formatted as they are, so an
+/// end tag before a value is kept, and a tag with a value in it keeps its
+/// quotes.
+///
+/// ```rust
+/// # use html_codegen_macros::html_format;
+/// # use pretty_assertions::assert_eq;
+/// let name = "Gal";
+///
+/// let formatted = html_format! {
+///     #![minify]
+///     <ul class="users">
+///         <li>{name}</li>
+///         <li>"Guest"</li>
+///     </ul>
+/// };
+///
+/// assert_eq!(formatted, "<ul class=users><li>Gal<li>Guest</ul>");
+/// ```
 #[proc_macro]
 #[proc_macro_error]
 pub fn html_format(input: TokenStream) -> TokenStream {
-    let element = parse_macro_input!(input as Element);
-    let args = element.into_minimized_formatter();
-
-    TokenStream::from(quote! {
-        format!("{}", #args)
-    })
+    let HtmlFormat { minify, element } = parse_macro_input!(input as HtmlFormat);
+    #[cfg(feature = "strict-html")]
+    let element = match strict_html::validate(element) {
+        Ok(element) => element,
+        Err(errors) => return errors.into(),
+    };
+    let args = element.to_minimized_formatter(minify);
+    let result = quote! { format!("{}", #args) };
+    #[cfg(feature = "strict-html")]
+    let result = strict_html::track_extra_attributes(result);
+    TokenStream::from(result)
 }
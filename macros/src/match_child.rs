@@ -0,0 +1,97 @@
+use crate::child::Child;
+use crate::children::Children;
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Result};
+
+/// A `match value { Pattern => <Element />, Pattern => { ... } }` child.
+pub struct MatchChild {
+    pub match_token: syn::Token![match],
+    pub value: syn::Expr,
+    pub arms: Vec<MatchArm>,
+}
+
+pub struct MatchArm {
+    pub pattern: syn::Pat,
+    pub guard: Option<syn::Expr>,
+    pub body: Children,
+}
+
+impl Parse for MatchArm {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let pattern = syn::Pat::parse_multi_with_leading_vert(input)?;
+
+        let guard = if input.parse::<Option<syn::Token![if]>>()?.is_some() {
+            Some(input.parse::<syn::Expr>()?)
+        } else {
+            None
+        };
+
+        input.parse::<syn::Token![=>]>()?;
+
+        let body = if input.peek(syn::token::Brace) {
+            Children::parse_braced(input)?
+        } else {
+            Children::new(vec![input.parse::<Child>()?])
+        };
+
+        input.parse::<Option<syn::Token![,]>>()?;
+
+        Ok(Self {
+            pattern,
+            guard,
+            body,
+        })
+    }
+}
+
+impl Parse for MatchChild {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let match_token = input.parse::<syn::Token![match]>()?;
+        let value = syn::Expr::parse_without_eager_brace(input)?;
+
+        let content;
+        syn::braced!(content in input);
+
+        let mut arms = vec![];
+        while !content.is_empty() {
+            arms.push(content.parse::<MatchArm>()?);
+        }
+
+        Ok(Self {
+            match_token,
+            value,
+            arms,
+        })
+    }
+}
+
+impl ToTokens for MatchChild {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let value = &self.value;
+        let last_index = self.arms.len().saturating_sub(1);
+
+        // Each arm gets its own position in a chain of nested `Either`s:
+        // `Left(a)`, `Right(Left(b))`, ..., `Right(Right(z))`.
+        let arms = self.arms.iter().enumerate().map(|(index, arm)| {
+            let pattern = &arm.pattern;
+            let guard = arm.guard.as_ref().map(|guard| quote! { if #guard });
+
+            let mut body = arm.body.as_tuple_tokens();
+            if index < last_index {
+                body = quote! { ::html_codegen::Either::Left(#body) };
+            }
+            for _ in 0..index {
+                body = quote! { ::html_codegen::Either::Right(#body) };
+            }
+
+            quote! { #pattern #guard => #body, }
+        });
+
+        quote! {
+            match #value {
+                #(#arms)*
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -0,0 +1,229 @@
+//! Mirrors `html_codegen`'s minified rendering, for the static chunks of
+//! `html_format! { #![minify] ... }`
+//!
+//! The values between the chunks are formatted as they are, so an end tag
+//! before a value is kept, and a tag with a value in it keeps its quotes.
+
+use html_codegen_syntax::{
+    has_optional_end_tag, is_preformatted_element, may_omit_before, may_omit_before_parent_end,
+    write_start_tag, Tag,
+};
+
+/// Minifies the static chunks of a formatter, in order
+pub struct Minifier {
+    /// The tag being minified, from its `<`
+    tag: String,
+    /// The quote of the attribute value being written in `tag`, if any
+    quote: Option<char>,
+    /// Whether a value was formatted in the tag, which is then written as
+    /// it is until its `>`
+    unfinished_tag: bool,
+    /// The name of an end tag that was left out, until what follows it
+    /// shows whether it can be
+    omitted: Option<String>,
+    /// The name of the `<script>` or `<style>` the text is in
+    raw_text: Option<String>,
+    /// The number of `<pre>` and `<textarea>` the text is in
+    preformatted: usize,
+    /// The whitespace written since the last tag, while there's nothing else
+    whitespace: Option<String>,
+}
+
+impl Default for Minifier {
+    fn default() -> Self {
+        Self {
+            tag: String::new(),
+            quote: None,
+            unfinished_tag: false,
+            omitted: None,
+            raw_text: None,
+            preformatted: 0,
+            whitespace: Some(String::new()),
+        }
+    }
+}
+
+impl Minifier {
+    /// Minifies a static chunk
+    pub fn chunk(&mut self, text: &str) -> String {
+        let mut output = String::new();
+        let mut rest = text;
+
+        while !rest.is_empty() {
+            if let Some(name) = &self.raw_text {
+                let closing_tag = format!("</{name}");
+                match rest.to_ascii_lowercase().find(&closing_tag) {
+                    Some(index) => {
+                        output.push_str(&rest[..index]);
+                        rest = &rest[index..];
+                        self.raw_text = None;
+                    }
+                    None => {
+                        output.push_str(rest);
+                        break;
+                    }
+                }
+            }
+
+            let Some(c) = rest.chars().next() else {
+                break;
+            };
+            rest = &rest[c.len_utf8()..];
+            self.push(c, &mut output);
+        }
+
+        output
+    }
+
+    /// What to write before a value, which could be anything
+    pub fn before_value(&mut self) -> String {
+        let mut output = String::new();
+
+        if !self.tag.is_empty() && !self.unfinished_tag {
+            let tag = std::mem::take(&mut self.tag);
+            if let Tag::Start(name) = Tag::parse(&tag) {
+                self.resolve_omitted(|omitted| may_omit_before(omitted, name), &mut output);
+                self.enter(name);
+            }
+            output.push_str(&tag);
+            self.unfinished_tag = true;
+        } else {
+            self.keep_whitespace(&mut output);
+        }
+
+        output
+    }
+
+    /// What's held back at the end. An end tag at the end is kept, as the
+    /// output may be part of a page.
+    pub fn finish(mut self) -> String {
+        let mut output = String::new();
+        self.collapse_whitespace(&mut output);
+        self.resolve_omitted(|_| false, &mut output);
+        output.push_str(&self.tag);
+        output
+    }
+
+    fn push(&mut self, c: char, output: &mut String) {
+        if self.unfinished_tag {
+            output.push(c);
+            match (self.quote, c) {
+                (Some(quote), c) if c == quote => self.quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') => self.quote = Some(c),
+                (None, '>') => {
+                    self.unfinished_tag = false;
+                    self.whitespace = Some(String::new());
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        if !self.tag.is_empty() {
+            let after_equals = self.tag.ends_with('=');
+            self.tag.push(c);
+
+            match (self.quote, c) {
+                (Some(quote), c) if c == quote => self.quote = None,
+                (Some(_), _) => {}
+                (None, '"' | '\'') if after_equals && !self.tag.starts_with("<!") => {
+                    self.quote = Some(c)
+                }
+                (None, '>') => {
+                    let tag = std::mem::take(&mut self.tag);
+                    self.push_tag(&tag, output);
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        if c == '<' {
+            self.collapse_whitespace(output);
+            self.tag.push(c);
+            return;
+        }
+
+        if let Some(whitespace) = &mut self.whitespace {
+            if self.preformatted == 0 && c.is_ascii_whitespace() {
+                whitespace.push(c);
+                return;
+            }
+        }
+
+        self.keep_whitespace(output);
+        output.push(c);
+    }
+
+    fn push_tag(&mut self, tag: &str, output: &mut String) {
+        self.whitespace = Some(String::new());
+
+        match Tag::parse(tag) {
+            Tag::End(name) => {
+                self.resolve_omitted(|omitted| may_omit_before_parent_end(omitted, name), output);
+
+                if is_preformatted_element(name) {
+                    self.preformatted = self.preformatted.saturating_sub(1);
+                }
+
+                if has_optional_end_tag(name) {
+                    self.omitted = Some(name.to_string());
+                } else {
+                    output.push_str(tag);
+                }
+            }
+
+            Tag::Start(name) => {
+                self.resolve_omitted(|omitted| may_omit_before(omitted, name), output);
+                self.enter(name);
+                // writing to a `String` can't fail
+                let _ = write_start_tag(tag, name, output);
+            }
+
+            Tag::Other => {
+                self.resolve_omitted(|_| false, output);
+                output.push_str(tag);
+            }
+        }
+    }
+
+    /// Keeps track of the contents of the element `name` just opened
+    fn enter(&mut self, name: &str) {
+        if is_preformatted_element(name) {
+            self.preformatted += 1;
+        }
+
+        if matches!(name, "script" | "style") {
+            self.raw_text = Some(name.to_string());
+        }
+    }
+
+    /// Writes the omitted end tag, unless `may_omit` says it can be left out
+    fn resolve_omitted(&mut self, may_omit: impl FnOnce(&str) -> bool, output: &mut String) {
+        if let Some(omitted) = self.omitted.take() {
+            if !may_omit(&omitted) {
+                output.push_str(&format!("</{omitted}>"));
+            }
+        }
+    }
+
+    /// Writes the whitespace held back as it is, as it's part of the text
+    /// that follows it
+    fn keep_whitespace(&mut self, output: &mut String) {
+        self.resolve_omitted(|_| false, output);
+        if let Some(whitespace) = self.whitespace.take() {
+            output.push_str(&whitespace);
+        }
+    }
+
+    /// Writes the whitespace held back between two tags as a single space
+    fn collapse_whitespace(&mut self, output: &mut String) {
+        if let Some(whitespace) = self.whitespace.take() {
+            if !whitespace.is_empty() {
+                self.resolve_omitted(|_| false, output);
+                output.push(' ');
+            }
+        }
+    }
+}
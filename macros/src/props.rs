@@ -0,0 +1,298 @@
+use proc_macro_error::emit_error;
+use quote::{format_ident, quote, quote_spanned};
+
+/// A field of a component, as seen by its props builder
+pub struct Prop {
+    pub ident: syn::Ident,
+    pub ty: syn::Type,
+    pub default: Option<syn::Expr>,
+    /// Whether the setter accepts anything that converts `Into` the type
+    pub into: bool,
+}
+
+impl Prop {
+    /// Reads the `#[prop(...)]` options of a field or function argument.
+    /// `Option` props default to `None` unless told otherwise.
+    pub fn new(ident: syn::Ident, ty: syn::Type, attrs: &[syn::Attribute]) -> Self {
+        let mut into = false;
+        let mut default = is_option(&ty).then(|| syn::parse_quote!(::core::option::Option::None));
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("prop")) {
+            let parsed = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    default = Some(if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::Expr>()?
+                    } else {
+                        syn::parse_quote!(::core::default::Default::default())
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("into") {
+                    into = true;
+                    Ok(())
+                } else {
+                    Err(meta.error(
+                        "Unknown prop option, expected `default`, `default = ...` or `into`",
+                    ))
+                }
+            });
+
+            if let Err(err) = parsed {
+                emit_error!(err.span(), "{}", err);
+            }
+        }
+
+        Self {
+            ident,
+            ty,
+            default,
+            into,
+        }
+    }
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// Removes the `#[prop(...)]` options, which aren't valid outside of
+/// `#[component]` and `#[derive(Props)]`
+pub fn without_prop_attributes(attrs: &[syn::Attribute]) -> Vec<&syn::Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| !attr.path().is_ident("prop"))
+        .collect()
+}
+
+/// The generic arguments matching some generic parameters, like `'a, T, N`
+fn generic_arguments(generics: &syn::Generics) -> Vec<proc_macro2::TokenStream> {
+    generics
+        .params
+        .iter()
+        .map(|param| match param {
+            syn::GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                quote!(#lifetime)
+            }
+            syn::GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                quote!(#ident)
+            }
+            syn::GenericParam::Const(constant) => {
+                let ident = &constant.ident;
+                quote!(#ident)
+            }
+        })
+        .collect()
+}
+
+/// Generates `Name::builder()`, which `rsx!` uses to create custom
+/// components, and the `NameBuilder` it returns.
+///
+/// Every prop gets a type parameter on the builder, which is either
+/// `Unset` or `Set<T>`, so `build` can require the props without defaults.
+pub fn create_props_builder(
+    vis: &syn::Visibility,
+    name: &syn::Ident,
+    generics: &syn::Generics,
+    props: Option<&[Prop]>,
+) -> proc_macro2::TokenStream {
+    let builder = format_ident!("{}Builder", name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let fields = props.unwrap_or_default();
+
+    let mut struct_generics = generics.clone();
+    for param in struct_generics.type_params_mut() {
+        param.eq_token = None;
+        param.default = None;
+    }
+    for param in struct_generics.const_params_mut() {
+        param.eq_token = None;
+        param.default = None;
+    }
+
+    let states: Vec<_> = (0..fields.len())
+        .map(|index| format_ident!("__P{}", index))
+        .collect();
+    let mut builder_generics = struct_generics.clone();
+    for state in &states {
+        builder_generics.params.push(syn::parse_quote!(#state));
+    }
+    let (builder_impl_generics, builder_ty_generics, builder_where_clause) =
+        builder_generics.split_for_impl();
+
+    let struct_arguments = generic_arguments(&struct_generics);
+    let phantoms = struct_generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                Some(quote!(&#lifetime ()))
+            }
+            syn::GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                Some(quote!(fn() -> #ident))
+            }
+            syn::GenericParam::Const(_) => None,
+        });
+    let marker = quote! {
+        __marker: ::core::marker::PhantomData<(#(#phantoms,)*)>
+    };
+
+    let idents: Vec<_> = fields.iter().map(|prop| &prop.ident).collect();
+
+    let setters = fields.iter().enumerate().map(|(index, prop)| {
+        let ident = &prop.ident;
+        let ty = &prop.ty;
+        let result_states = states.iter().enumerate().map(|(state_index, state)| {
+            if state_index == index {
+                quote!(::html_codegen::props::Set<#ty>)
+            } else {
+                quote!(#state)
+            }
+        });
+        let other_fields = idents.iter().filter(|other| *other != &ident);
+        let (argument_ty, value) = if prop.into {
+            (
+                quote!(impl ::core::convert::Into<#ty>),
+                quote!(::core::convert::Into::into(#ident)),
+            )
+        } else {
+            (quote!(#ty), quote!(#ident))
+        };
+
+        quote! {
+            #[allow(clippy::type_complexity)]
+            #vis fn #ident(self, #ident: #argument_ty) -> #builder<#(#struct_arguments,)* #(#result_states),*> {
+                #builder {
+                    #ident: ::html_codegen::props::Set(#value),
+                    #(#other_fields: self.#other_fields,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+    });
+
+    let build_bounds = fields.iter().zip(&states).map(|(prop, state)| {
+        let ty = &prop.ty;
+        match prop.default {
+            Some(_) => quote!(#state: ::html_codegen::props::Optional<#ty>),
+            None => {
+                quote_spanned!(prop.ident.span()=> #state: ::html_codegen::props::Required<#ty>)
+            }
+        }
+    });
+    let build_where_clause = match builder_where_clause {
+        Some(where_clause) => {
+            let predicates = where_clause.predicates.iter();
+            quote!(where #(#predicates,)* #(#build_bounds),*)
+        }
+        None => quote!(where #(#build_bounds),*),
+    };
+
+    let built_fields = fields.iter().map(|prop| {
+        let ident = &prop.ident;
+        match &prop.default {
+            Some(default) => quote! {
+                #ident: ::html_codegen::props::Optional::value_or_else(self.#ident, || #default)
+            },
+            None => quote! {
+                #ident: ::html_codegen::props::Required::into_value(self.#ident)
+            },
+        }
+    });
+    let built = match props {
+        Some(_) => quote!(#name { #(#built_fields),* }),
+        None => quote!(#name),
+    };
+
+    let builder_doc = format!("Builds [`{}`] one prop at a time", name);
+    let unset_states: Vec<_> = states
+        .iter()
+        .map(|_| quote!(::html_codegen::props::Unset))
+        .collect();
+
+    quote! {
+        #[doc = #builder_doc]
+        #vis struct #builder #builder_generics #builder_where_clause {
+            #(#idents: #states,)*
+            #marker
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #[allow(clippy::type_complexity)]
+            #vis fn builder() -> #builder<#(#struct_arguments,)* #(#unset_states),*> {
+                #builder {
+                    #(#idents: ::html_codegen::props::Unset,)*
+                    __marker: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl #builder_impl_generics #builder #builder_ty_generics #builder_where_clause {
+            #(#setters)*
+        }
+
+        impl #builder_impl_generics #builder #builder_ty_generics #build_where_clause {
+            #vis fn build(self) -> #name #ty_generics {
+                #built
+            }
+        }
+
+        impl #impl_generics ::html_codegen::props::Props for #name #ty_generics #where_clause {
+            type Builder = #builder<#(#struct_arguments,)* #(#unset_states),*>;
+
+            fn builder() -> Self::Builder {
+                Self::builder()
+            }
+        }
+
+        impl #builder_impl_generics ::html_codegen::props::Builder for #builder #builder_ty_generics #builder_where_clause {
+            type Component = #name #ty_generics;
+        }
+
+        impl #builder_impl_generics ::html_codegen::props::Build for #builder #builder_ty_generics #build_where_clause {
+            fn build(self) -> Self::Component {
+                Self::build(self)
+            }
+        }
+    }
+}
+
+/// `#[derive(Props)]` for structs with named fields and unit structs
+pub fn derive_props(input: syn::DeriveInput) -> proc_macro2::TokenStream {
+    let props: Option<Vec<Prop>> = match &input.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => Some(
+            fields
+                .named
+                .iter()
+                .map(|field| {
+                    let ident = field.ident.clone().unwrap();
+                    Prop::new(ident, field.ty.clone(), &field.attrs)
+                })
+                .collect(),
+        ),
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Unit,
+            ..
+        }) => None,
+        _ => {
+            emit_error!(
+                input.ident.span(),
+                "Props can only be derived for structs with named fields, or unit structs"
+            );
+            return quote!();
+        }
+    };
+
+    create_props_builder(&input.vis, &input.ident, &input.generics, props.as_deref())
+}
@@ -0,0 +1,56 @@
+use crate::children::Children;
+use proc_macro_error::abort;
+use syn::parse::{Parse, ParseStream, Result};
+
+/// A `<slot:name>...</slot:name>` child of a custom component, passing its
+/// children as the component's `name` field.
+pub struct Slot {
+    pub name: syn::Ident,
+    pub children: Children,
+}
+
+impl Slot {
+    pub fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.parse::<syn::Token![<]>().is_ok()
+            && fork
+                .parse::<syn::Ident>()
+                .is_ok_and(|ident| ident == "slot")
+            && fork.peek(syn::Token![:])
+            && !fork.peek(syn::Token![::])
+    }
+}
+
+fn parse_slot_name(input: ParseStream) -> Result<syn::Ident> {
+    let slot = input.parse::<syn::Ident>()?;
+    if slot != "slot" {
+        return Err(syn::Error::new(slot.span(), "Expected `slot:`"));
+    }
+    input.parse::<syn::Token![:]>()?;
+    input.parse::<syn::Ident>()
+}
+
+impl Parse for Slot {
+    fn parse(input: ParseStream) -> Result<Self> {
+        input.parse::<syn::Token![<]>()?;
+        let name = parse_slot_name(input)?;
+        input.parse::<syn::Token![>]>()?;
+
+        let children = input.parse::<Children>()?;
+
+        input.parse::<syn::Token![<]>()?;
+        input.parse::<syn::Token![/]>()?;
+        let closing_name = parse_slot_name(input)?;
+        input.parse::<syn::Token![>]>()?;
+
+        if closing_name != name {
+            abort!(
+                closing_name.span(),
+                "Expected closing tag for: <slot:{}>",
+                name
+            );
+        }
+
+        Ok(Self { name, children })
+    }
+}
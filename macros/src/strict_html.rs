@@ -0,0 +1,1038 @@
+//! Checks of lowercase tag names and their attributes against the HTML
+//! Living Standard, enabled by the `strict-html` feature.
+//!
+//! `data-*` and `aria-*` attributes are always allowed, and so is anything
+//! listed in the `HTML_CODEGEN_EXTRA_ATTRIBUTES` environment variable. The
+//! children of `<svg>` and `<math>` are foreign content, and the children of
+//! `Xml` are XML, so they aren't checked.
+//!
+//! Enumerated attributes only accept one of their keywords as a literal, and
+//! otherwise a value of their `html_codegen::enumerated` enum.
+
+use crate::child::Child;
+use crate::children::Children;
+use crate::element::Element;
+use crate::element_attribute::{attribute_name, ElementAttribute};
+use crate::if_child::ElseBranch;
+use proc_macro2::TokenStream;
+use proc_macro_error::{Diagnostic, Level};
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
+
+/// A comma separated list of extra attributes to allow, where a trailing
+/// `*` allows every attribute with that prefix, like `hx-*, x-*`
+const EXTRA_ATTRIBUTES_VAR: &str = "HTML_CODEGEN_EXTRA_ATTRIBUTES";
+
+/// Elements whose children are SVG or MathML rather than HTML
+const FOREIGN_ELEMENTS: &[&str] = &["svg", "math"];
+
+const ELEMENTS: &[&str] = &[
+    "a",
+    "abbr",
+    "address",
+    "area",
+    "article",
+    "aside",
+    "audio",
+    "b",
+    "base",
+    "bdi",
+    "bdo",
+    "blockquote",
+    "body",
+    "br",
+    "button",
+    "canvas",
+    "caption",
+    "cite",
+    "code",
+    "col",
+    "colgroup",
+    "data",
+    "datalist",
+    "dd",
+    "del",
+    "details",
+    "dfn",
+    "dialog",
+    "div",
+    "dl",
+    "dt",
+    "em",
+    "embed",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "head",
+    "header",
+    "hgroup",
+    "hr",
+    "html",
+    "i",
+    "iframe",
+    "img",
+    "input",
+    "ins",
+    "kbd",
+    "label",
+    "legend",
+    "li",
+    "link",
+    "main",
+    "map",
+    "mark",
+    "math",
+    "menu",
+    "meta",
+    "meter",
+    "nav",
+    "noscript",
+    "object",
+    "ol",
+    "optgroup",
+    "option",
+    "output",
+    "p",
+    "picture",
+    "pre",
+    "progress",
+    "q",
+    "rp",
+    "rt",
+    "ruby",
+    "s",
+    "samp",
+    "script",
+    "search",
+    "section",
+    "select",
+    "slot",
+    "small",
+    "source",
+    "span",
+    "strong",
+    "style",
+    "sub",
+    "summary",
+    "sup",
+    "svg",
+    "table",
+    "tbody",
+    "td",
+    "template",
+    "textarea",
+    "tfoot",
+    "th",
+    "thead",
+    "time",
+    "title",
+    "tr",
+    "track",
+    "u",
+    "ul",
+    "var",
+    "video",
+    "wbr",
+];
+
+const GLOBAL_ATTRIBUTES: &[&str] = &[
+    "accesskey",
+    "autocapitalize",
+    "autocorrect",
+    "autofocus",
+    "class",
+    "contenteditable",
+    "dir",
+    "draggable",
+    "enterkeyhint",
+    "hidden",
+    "id",
+    "inert",
+    "inputmode",
+    "is",
+    "itemid",
+    "itemprop",
+    "itemref",
+    "itemscope",
+    "itemtype",
+    "lang",
+    "nonce",
+    "popover",
+    "role",
+    "slot",
+    "spellcheck",
+    "style",
+    "tabindex",
+    "title",
+    "translate",
+    "writingsuggestions",
+    "xml:lang",
+];
+
+/// The event handler attributes every element accepts, including the ones
+/// defined by the Pointer Events, Touch Events and CSS specifications
+const EVENT_HANDLER_ATTRIBUTES: &[&str] = &[
+    "onabort",
+    "onafterprint",
+    "onanimationcancel",
+    "onanimationend",
+    "onanimationiteration",
+    "onanimationstart",
+    "onauxclick",
+    "onbeforeinput",
+    "onbeforematch",
+    "onbeforeprint",
+    "onbeforetoggle",
+    "onbeforeunload",
+    "onblur",
+    "oncancel",
+    "oncanplay",
+    "oncanplaythrough",
+    "onchange",
+    "onclick",
+    "onclose",
+    "oncommand",
+    "oncontextlost",
+    "oncontextmenu",
+    "oncontextrestored",
+    "oncopy",
+    "oncuechange",
+    "oncut",
+    "ondblclick",
+    "ondrag",
+    "ondragend",
+    "ondragenter",
+    "ondragleave",
+    "ondragover",
+    "ondragstart",
+    "ondrop",
+    "ondurationchange",
+    "onemptied",
+    "onended",
+    "onerror",
+    "onfocus",
+    "onfocusin",
+    "onfocusout",
+    "onformdata",
+    "onhashchange",
+    "oninput",
+    "oninvalid",
+    "onkeydown",
+    "onkeypress",
+    "onkeyup",
+    "onlanguagechange",
+    "onload",
+    "onloadeddata",
+    "onloadedmetadata",
+    "onloadstart",
+    "onmessage",
+    "onmessageerror",
+    "onmousedown",
+    "onmouseenter",
+    "onmouseleave",
+    "onmousemove",
+    "onmouseout",
+    "onmouseover",
+    "onmouseup",
+    "onoffline",
+    "ononline",
+    "onpagehide",
+    "onpagereveal",
+    "onpageshow",
+    "onpageswap",
+    "onpaste",
+    "onpause",
+    "onplay",
+    "onplaying",
+    "onpointercancel",
+    "onpointerdown",
+    "onpointerenter",
+    "onpointerleave",
+    "onpointermove",
+    "onpointerout",
+    "onpointerover",
+    "onpointerup",
+    "onpopstate",
+    "onprogress",
+    "onratechange",
+    "onrejectionhandled",
+    "onreset",
+    "onresize",
+    "onscroll",
+    "onscrollend",
+    "onsecuritypolicyviolation",
+    "onseeked",
+    "onseeking",
+    "onselect",
+    "onslotchange",
+    "onstalled",
+    "onstorage",
+    "onsubmit",
+    "onsuspend",
+    "ontimeupdate",
+    "ontoggle",
+    "ontouchcancel",
+    "ontouchend",
+    "ontouchmove",
+    "ontouchstart",
+    "ontransitioncancel",
+    "ontransitionend",
+    "ontransitionrun",
+    "ontransitionstart",
+    "onunhandledrejection",
+    "onunload",
+    "onvolumechange",
+    "onwaiting",
+    "onwheel",
+];
+
+/// The attributes specific to some elements, and the elements accepting them
+const ELEMENT_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("abbr", &["th"]),
+    ("accept", &["input"]),
+    ("accept-charset", &["form"]),
+    ("action", &["form"]),
+    ("allow", &["iframe"]),
+    ("allowfullscreen", &["iframe"]),
+    ("alpha", &["input"]),
+    ("alt", &["area", "img", "input"]),
+    ("as", &["link"]),
+    ("async", &["script"]),
+    ("autocomplete", &["form", "input", "select", "textarea"]),
+    ("autoplay", &["audio", "video"]),
+    ("blocking", &["link", "script", "style"]),
+    ("charset", &["meta"]),
+    ("checked", &["input"]),
+    ("cite", &["blockquote", "del", "ins", "q"]),
+    ("closedby", &["dialog"]),
+    ("color", &["link"]),
+    ("colorspace", &["input"]),
+    ("cols", &["textarea"]),
+    ("colspan", &["td", "th"]),
+    ("command", &["button"]),
+    ("commandfor", &["button"]),
+    ("content", &["meta"]),
+    ("controls", &["audio", "video"]),
+    ("coords", &["area"]),
+    ("crossorigin", &["audio", "img", "link", "script", "video"]),
+    ("data", &["object"]),
+    ("datetime", &["del", "ins", "time"]),
+    ("decoding", &["img"]),
+    ("default", &["track"]),
+    ("defer", &["script"]),
+    ("dirname", &["input", "textarea"]),
+    (
+        "disabled",
+        &[
+            "button", "fieldset", "input", "link", "optgroup", "option", "select", "textarea",
+        ],
+    ),
+    ("download", &["a", "area"]),
+    ("enctype", &["form"]),
+    ("fetchpriority", &["img", "link", "script"]),
+    ("for", &["label", "output"]),
+    (
+        "form",
+        &[
+            "button", "fieldset", "input", "object", "output", "select", "textarea",
+        ],
+    ),
+    ("formaction", &["button", "input"]),
+    ("formenctype", &["button", "input"]),
+    ("formmethod", &["button", "input"]),
+    ("formnovalidate", &["button", "input"]),
+    ("formtarget", &["button", "input"]),
+    ("headers", &["td", "th"]),
+    (
+        "height",
+        &[
+            "canvas", "embed", "iframe", "img", "input", "object", "source", "video",
+        ],
+    ),
+    ("high", &["meter"]),
+    ("href", &["a", "area", "base", "link"]),
+    ("hreflang", &["a", "link"]),
+    ("http-equiv", &["meta"]),
+    ("imagesizes", &["link"]),
+    ("imagesrcset", &["link"]),
+    ("integrity", &["link", "script"]),
+    ("ismap", &["img"]),
+    ("kind", &["track"]),
+    ("label", &["optgroup", "option", "track"]),
+    ("list", &["input"]),
+    ("loading", &["iframe", "img"]),
+    ("loop", &["audio", "video"]),
+    ("low", &["meter"]),
+    ("max", &["input", "meter", "progress"]),
+    ("maxlength", &["input", "textarea"]),
+    ("media", &["link", "meta", "source", "style"]),
+    ("method", &["form"]),
+    ("min", &["input", "meter"]),
+    ("minlength", &["input", "textarea"]),
+    ("multiple", &["input", "select"]),
+    ("muted", &["audio", "video"]),
+    (
+        "name",
+        &[
+            "button", "details", "fieldset", "form", "iframe", "input", "map", "meta", "object",
+            "output", "select", "slot", "textarea",
+        ],
+    ),
+    ("nomodule", &["script"]),
+    ("novalidate", &["form"]),
+    ("open", &["details", "dialog"]),
+    ("optimum", &["meter"]),
+    ("pattern", &["input"]),
+    ("ping", &["a", "area"]),
+    ("placeholder", &["input", "textarea"]),
+    ("playsinline", &["video"]),
+    ("popovertarget", &["button", "input"]),
+    ("popovertargetaction", &["button", "input"]),
+    ("poster", &["video"]),
+    ("preload", &["audio", "video"]),
+    ("readonly", &["input", "textarea"]),
+    (
+        "referrerpolicy",
+        &["a", "area", "iframe", "img", "link", "script"],
+    ),
+    ("rel", &["a", "area", "form", "link"]),
+    ("required", &["input", "select", "textarea"]),
+    ("reversed", &["ol"]),
+    ("rows", &["textarea"]),
+    ("rowspan", &["td", "th"]),
+    ("sandbox", &["iframe"]),
+    ("scope", &["th"]),
+    ("selected", &["option"]),
+    ("shadowrootclonable", &["template"]),
+    ("shadowrootcustomelementregistry", &["template"]),
+    ("shadowrootdelegatesfocus", &["template"]),
+    ("shadowrootmode", &["template"]),
+    ("shadowrootserializable", &["template"]),
+    ("shape", &["area"]),
+    ("size", &["input", "select"]),
+    ("sizes", &["img", "link", "source"]),
+    ("span", &["col", "colgroup"]),
+    (
+        "src",
+        &[
+            "audio", "embed", "iframe", "img", "input", "script", "source", "track", "video",
+        ],
+    ),
+    ("srcdoc", &["iframe"]),
+    ("srclang", &["track"]),
+    ("srcset", &["img", "source"]),
+    ("start", &["ol"]),
+    ("step", &["input"]),
+    ("target", &["a", "area", "base", "form"]),
+    (
+        "type",
+        &[
+            "a", "button", "embed", "input", "link", "object", "ol", "script", "source",
+        ],
+    ),
+    ("usemap", &["img"]),
+    (
+        "value",
+        &[
+            "button", "data", "input", "li", "meter", "option", "output", "progress",
+        ],
+    ),
+    (
+        "width",
+        &[
+            "canvas", "embed", "iframe", "img", "input", "object", "source", "video",
+        ],
+    ),
+    ("wrap", &["textarea"]),
+    ("xmlns", &["html"]),
+];
+
+/// An attribute that only accepts some keywords
+struct EnumeratedAttribute {
+    name: &'static str,
+    elements: &'static [&'static str],
+    /// The enum of `html_codegen::enumerated` naming the keywords
+    enumeration: &'static str,
+    keywords: &'static [&'static str],
+    /// Whether the value is a space separated list of keywords
+    token_list: bool,
+}
+
+const INPUT_TYPES: &[&str] = &[
+    "button",
+    "checkbox",
+    "color",
+    "date",
+    "datetime-local",
+    "email",
+    "file",
+    "hidden",
+    "image",
+    "month",
+    "number",
+    "password",
+    "radio",
+    "range",
+    "reset",
+    "search",
+    "submit",
+    "tel",
+    "text",
+    "time",
+    "url",
+    "week",
+];
+const TARGETS: &[&str] = &["_blank", "_self", "_parent", "_top"];
+const METHODS: &[&str] = &["get", "post", "dialog"];
+
+const ENUMERATED_ATTRIBUTES: &[EnumeratedAttribute] = &[
+    EnumeratedAttribute {
+        name: "type",
+        elements: &["input"],
+        enumeration: "InputType",
+        keywords: INPUT_TYPES,
+        token_list: false,
+    },
+    EnumeratedAttribute {
+        name: "type",
+        elements: &["button"],
+        enumeration: "ButtonType",
+        keywords: &["submit", "reset", "button"],
+        token_list: false,
+    },
+    EnumeratedAttribute {
+        name: "target",
+        elements: &["a", "area", "base", "form"],
+        enumeration: "Target",
+        keywords: TARGETS,
+        token_list: false,
+    },
+    EnumeratedAttribute {
+        name: "formtarget",
+        elements: &["button", "input"],
+        enumeration: "Target",
+        keywords: TARGETS,
+        token_list: false,
+    },
+    EnumeratedAttribute {
+        name: "rel",
+        elements: &["a", "area", "form", "link"],
+        enumeration: "Rel",
+        keywords: &[
+            "alternate",
+            "author",
+            "bookmark",
+            "canonical",
+            "dns-prefetch",
+            "external",
+            "help",
+            "icon",
+            "license",
+            "manifest",
+            "me",
+            "modulepreload",
+            "next",
+            "nofollow",
+            "noopener",
+            "noreferrer",
+            "opener",
+            "pingback",
+            "preconnect",
+            "prefetch",
+            "preload",
+            "prev",
+            "privacy-policy",
+            "search",
+            "stylesheet",
+            "tag",
+            "terms-of-service",
+        ],
+        token_list: true,
+    },
+    EnumeratedAttribute {
+        name: "loading",
+        elements: &["iframe", "img"],
+        enumeration: "Loading",
+        keywords: &["eager", "lazy"],
+        token_list: false,
+    },
+    EnumeratedAttribute {
+        name: "method",
+        elements: &["form"],
+        enumeration: "Method",
+        keywords: METHODS,
+        token_list: false,
+    },
+    EnumeratedAttribute {
+        name: "formmethod",
+        elements: &["button", "input"],
+        enumeration: "Method",
+        keywords: METHODS,
+        token_list: false,
+    },
+    EnumeratedAttribute {
+        name: "autocomplete",
+        elements: &["form", "input", "select", "textarea"],
+        enumeration: "Autocomplete",
+        keywords: &[
+            "on",
+            "off",
+            "shipping",
+            "billing",
+            "home",
+            "work",
+            "mobile",
+            "fax",
+            "pager",
+            "name",
+            "honorific-prefix",
+            "given-name",
+            "additional-name",
+            "family-name",
+            "honorific-suffix",
+            "nickname",
+            "username",
+            "new-password",
+            "current-password",
+            "one-time-code",
+            "organization-title",
+            "organization",
+            "street-address",
+            "address-line1",
+            "address-line2",
+            "address-line3",
+            "address-level4",
+            "address-level3",
+            "address-level2",
+            "address-level1",
+            "country",
+            "country-name",
+            "postal-code",
+            "cc-name",
+            "cc-given-name",
+            "cc-additional-name",
+            "cc-family-name",
+            "cc-number",
+            "cc-exp",
+            "cc-exp-month",
+            "cc-exp-year",
+            "cc-csc",
+            "cc-type",
+            "transaction-currency",
+            "transaction-amount",
+            "language",
+            "bday",
+            "bday-day",
+            "bday-month",
+            "bday-year",
+            "sex",
+            "url",
+            "photo",
+            "tel",
+            "tel-country-code",
+            "tel-national",
+            "tel-area-code",
+            "tel-local",
+            "tel-extension",
+            "email",
+            "impp",
+            "webauthn",
+        ],
+        token_list: true,
+    },
+];
+
+impl EnumeratedAttribute {
+    fn find(tag: &str, name: &str) -> Option<&'static Self> {
+        ENUMERATED_ATTRIBUTES
+            .iter()
+            .find(|attribute| attribute.name == name && attribute.elements.contains(&tag))
+    }
+
+    /// The first word of a literal value that isn't a keyword
+    fn invalid_keyword<'v>(&self, value: &'v str) -> Option<&'v str> {
+        let mut words = if self.token_list {
+            value.split_ascii_whitespace().collect()
+        } else {
+            vec![value]
+        };
+
+        words.retain(|word| {
+            let is_keyword = self.keywords.contains(&word.to_ascii_lowercase().as_str());
+            // `target` also accepts the name of a browsing context, and
+            // `autocomplete` the name of a section
+            let is_name = match self.name {
+                "target" | "formtarget" => !word.starts_with('_'),
+                "autocomplete" => word.starts_with("section-"),
+                _ => false,
+            };
+            !is_keyword && !is_name
+        });
+
+        words.first().copied()
+    }
+
+    /// Checks a literal value, or wraps any other value so it has to be a
+    /// value of the enum
+    fn check(
+        &self,
+        tag: &str,
+        attribute: ElementAttribute,
+        errors: &mut Vec<Diagnostic>,
+    ) -> ElementAttribute {
+        let key = match &attribute {
+            ElementAttribute::Punned(key)
+            | ElementAttribute::WithValue(key, _)
+            | ElementAttribute::Literal(key, _) => key.clone(),
+            ElementAttribute::Directive(..) => return attribute,
+        };
+
+        match attribute.literal() {
+            Some(syn::Lit::Str(literal)) => {
+                let value = literal.value();
+                if let Some(word) = self.invalid_keyword(&value) {
+                    let error = Diagnostic::spanned(
+                        literal.span(),
+                        Level::Error,
+                        format!("`{}` is not a `{}` keyword of `<{}>`", word, self.name, tag),
+                    );
+                    errors.push(match closest(word, self.keywords.iter().copied()) {
+                        Some(keyword) => error.help(format!("did you mean `{}`?", keyword)),
+                        None => error.help(format!(
+                            "values that aren't one of the `{}` keywords can be wrapped in `html_codegen::enumerated::Unchecked`",
+                            self.enumeration
+                        )),
+                    });
+                }
+                attribute
+            }
+            Some(literal) => {
+                errors.push(Diagnostic::spanned(
+                    literal.span(),
+                    Level::Error,
+                    format!(
+                        "`{}` of `<{}>` expects one of the `{}` keywords",
+                        self.name, tag, self.enumeration
+                    ),
+                ));
+                attribute
+            }
+            None => {
+                let value = attribute.value_tokens();
+                let enumeration = syn::Ident::new(self.enumeration, value.span());
+                let checked = quote_spanned! {value.span()=>
+                    {
+                        ::html_codegen::enumerated::expect_value::<
+                            ::html_codegen::enumerated::#enumeration,
+                            _,
+                        >(#value)
+                    }
+                };
+
+                ElementAttribute::WithValue(key, syn::parse2(checked).unwrap())
+            }
+        }
+    }
+}
+
+/// Checks every lowercase tag name and attribute in the tree, and requires
+/// the enumerated attributes to have one of their keywords
+///
+/// The errors are returned in a block rather than emitted, because a macro in
+/// expression position can only expand to a single `compile_error!` of its
+/// own.
+pub fn validate(mut element: Element) -> Result<Element, TokenStream> {
+    let extra_attributes = std::env::var(EXTRA_ATTRIBUTES_VAR).unwrap_or_default();
+    let mut validator = Validator {
+        extra_attributes: extra_attributes
+            .split(',')
+            .map(str::trim)
+            .filter(|extra| !extra.is_empty())
+            .collect(),
+        errors: Vec::new(),
+    };
+
+    validator.element(&mut element);
+
+    let errors = validator.errors;
+    if errors.is_empty() {
+        Ok(element)
+    } else {
+        Err(quote! {{ #(#errors)* }})
+    }
+}
+
+/// Makes the output of a macro read `EXTRA_ATTRIBUTES_VAR` too, so Cargo
+/// rebuilds the crates that use it when the variable changes. Cargo only
+/// tracks the variables that a crate reads with `env!`, and not the ones that
+/// procedural macros read.
+///
+/// The output is the second value of a tuple rather than the tail of a block,
+/// so its temporaries live as long as they did without it.
+pub fn track_extra_attributes(output: TokenStream) -> TokenStream {
+    quote! { (::core::option_env!(#EXTRA_ATTRIBUTES_VAR), #output).1 }
+}
+
+struct Validator<'a> {
+    extra_attributes: Vec<&'a str>,
+    errors: Vec<Diagnostic>,
+}
+
+impl Validator<'_> {
+    fn element(&mut self, element: &mut Element) {
+        let tag = match element.name.get_ident() {
+            Some(ident) if !element.is_custom_element() => ident.unraw().to_string(),
+            // The children of `html_codegen::xml::Xml` are XML
+            _ if is_xml(element) => return,
+            _ => return self.children(&mut element.children),
+        };
+
+        if !ELEMENTS.contains(&tag.as_str()) {
+            self.unknown(&element.name, "element", &tag, ELEMENTS.iter().copied());
+            return;
+        }
+
+        // SVG and MathML have attributes of their own
+        if FOREIGN_ELEMENTS.contains(&tag.as_str()) {
+            return;
+        }
+
+        for attribute in &element.attributes.attributes {
+            // `class:` and `style:` directives render global attributes
+            if let ElementAttribute::Directive(..) = attribute {
+                continue;
+            }
+
+            let key = attribute.ident();
+            let name = attribute_name(key);
+            if is_known_attribute(&tag, &name, &self.extra_attributes) {
+                continue;
+            }
+
+            if ELEMENT_ATTRIBUTES.iter().any(|(known, _)| *known == name) {
+                self.errors.push(Diagnostic::spanned(
+                    key.span(),
+                    Level::Error,
+                    format!("`{}` is not an attribute of `<{}>`", name, tag),
+                ));
+            } else {
+                let candidates = GLOBAL_ATTRIBUTES
+                    .iter()
+                    .chain(EVENT_HANDLER_ATTRIBUTES)
+                    .copied()
+                    .chain(
+                        ELEMENT_ATTRIBUTES
+                            .iter()
+                            .filter(|(_, elements)| elements.contains(&tag.as_str()))
+                            .map(|(known, _)| *known),
+                    );
+                self.unknown(key, "attribute", &name, candidates);
+            }
+        }
+
+        element.attributes.attributes = element
+            .attributes
+            .attributes
+            .drain()
+            .map(|attribute| {
+                match EnumeratedAttribute::find(&tag, &attribute_name(attribute.ident())) {
+                    Some(enumerated) => enumerated.check(&tag, attribute, &mut self.errors),
+                    None => attribute,
+                }
+            })
+            .collect();
+
+        self.children(&mut element.children);
+    }
+
+    fn children(&mut self, children: &mut Children) {
+        for child in &mut children.nodes {
+            match child {
+                Child::Element(element) => self.element(element),
+                Child::If(if_child) => {
+                    let mut if_child = if_child;
+                    loop {
+                        self.children(&mut if_child.then_branch);
+                        match &mut if_child.else_branch {
+                            Some(ElseBranch::If(else_if)) => if_child = else_if,
+                            Some(ElseBranch::Else(children)) => break self.children(children),
+                            None => break,
+                        }
+                    }
+                }
+                Child::For(for_child) => self.children(&mut for_child.body),
+                Child::Match(match_child) => {
+                    for arm in &mut match_child.arms {
+                        self.children(&mut arm.body);
+                    }
+                }
+                Child::Slot(slot) => self.children(&mut slot.children),
+                Child::RawBlock(_) | Child::Text(_) | Child::Format(_) | Child::Comment(_) => {}
+            }
+        }
+    }
+
+    /// An error for an unknown name, suggesting the closest candidate
+    fn unknown<'c>(
+        &mut self,
+        span: impl Spanned,
+        kind: &str,
+        name: &str,
+        candidates: impl Iterator<Item = &'c str>,
+    ) {
+        let error = Diagnostic::spanned(
+            span.span(),
+            Level::Error,
+            format!("Unknown HTML {} `{}`", kind, name),
+        );
+        self.errors.push(match (kind, closest(name, candidates)) {
+            (_, Some(candidate)) => error.help(format!("did you mean `{}`?", candidate)),
+            ("attribute", None) => error.help(format!(
+                "extra attributes can be allowed with the `{}` environment variable",
+                EXTRA_ATTRIBUTES_VAR
+            )),
+            (_, None) => error,
+        });
+    }
+}
+
+fn is_xml(element: &Element) -> bool {
+    element
+        .name
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "Xml")
+}
+
+fn is_known_attribute(tag: &str, name: &str, extra_attributes: &[&str]) -> bool {
+    name.starts_with("data-")
+        || name.starts_with("aria-")
+        || GLOBAL_ATTRIBUTES.contains(&name)
+        || EVENT_HANDLER_ATTRIBUTES.contains(&name)
+        || ELEMENT_ATTRIBUTES
+            .iter()
+            .any(|(known, elements)| *known == name && elements.contains(&tag))
+        || extra_attributes
+            .iter()
+            .any(|extra| match extra.strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => *extra == name,
+            })
+}
+
+/// The candidate closest to `name`, if it's close enough to be a typo
+fn closest<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= (name.len() / 3).max(1))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The number of insertions, deletions, substitutions and swaps of adjacent
+/// characters turning `a` into `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        distances[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn knows_element_and_global_attributes() {
+        assert!(is_known_attribute("a", "href", &[]));
+        assert!(is_known_attribute("div", "class", &[]));
+        assert!(is_known_attribute("div", "onclick", &[]));
+        assert!(is_known_attribute("div", "data-user-id", &[]));
+        assert!(is_known_attribute("div", "aria-label", &[]));
+        assert!(!is_known_attribute("div", "href", &[]));
+        assert!(!is_known_attribute("div", "clas", &[]));
+    }
+
+    #[test]
+    fn allows_extra_attributes() {
+        let extra = ["hx-*", "@*", "up-target"];
+
+        assert!(is_known_attribute("div", "hx-get", &extra));
+        assert!(is_known_attribute("div", "hx-on::after-request", &extra));
+        assert!(is_known_attribute("div", "@click.prevent", &extra));
+        assert!(is_known_attribute("div", "up-target", &extra));
+        assert!(!is_known_attribute("div", "up-follow", &extra));
+    }
+
+    #[test]
+    fn checks_enumerated_keywords() {
+        let input_type = EnumeratedAttribute::find("input", "type").unwrap();
+        let target = EnumeratedAttribute::find("a", "target").unwrap();
+        let rel = EnumeratedAttribute::find("link", "rel").unwrap();
+
+        assert_eq!(
+            EnumeratedAttribute::find("button", "type")
+                .unwrap()
+                .enumeration,
+            "ButtonType"
+        );
+        assert!(EnumeratedAttribute::find("script", "type").is_none());
+        assert_eq!(input_type.invalid_keyword("Email"), None);
+        assert_eq!(input_type.invalid_keyword("emial"), Some("emial"));
+        assert_eq!(target.invalid_keyword("preview"), None);
+        assert_eq!(target.invalid_keyword("_new"), Some("_new"));
+        assert_eq!(rel.invalid_keyword("preload  stylesheet"), None);
+        assert_eq!(
+            rel.invalid_keyword("preload stylesheat"),
+            Some("stylesheat")
+        );
+    }
+
+    #[test]
+    fn suggests_close_names() {
+        assert_eq!(closest("dvi", ELEMENTS.iter().copied()), Some("div"));
+        assert_eq!(
+            closest("sectoin", ELEMENTS.iter().copied()),
+            Some("section")
+        );
+        assert_eq!(closest("marquee", ELEMENTS.iter().copied()), None);
+        assert_eq!(
+            closest("clas", GLOBAL_ATTRIBUTES.iter().copied()),
+            Some("class")
+        );
+        assert_eq!(
+            closest("onclik", EVENT_HANDLER_ATTRIBUTES.iter().copied()),
+            Some("onclick")
+        );
+    }
+}
@@ -8,7 +8,6 @@ pub struct OpenTag {
     pub name: syn::Path,
     pub attributes: ElementAttributes,
     pub self_closing: bool,
-    pub is_custom_element: bool,
 }
 
 fn name_or_fragment(maybe_name: Result<syn::Path>) -> syn::Path {
@@ -40,7 +39,6 @@ impl Parse for OpenTag {
             name,
             attributes,
             self_closing,
-            is_custom_element,
         })
     }
 }
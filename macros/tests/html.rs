@@ -25,9 +25,9 @@ fn single_regular_tag_with_value_child() {
 
 #[test]
 fn single_regular_tag_with_void_attribute() {
-    let output: String = html_format! { <div checked></div> };
+    let output: String = html_format! { <details open></details> };
 
-    assert_eq!(output.as_str(), "<div checked></div>");
+    assert_eq!(output.as_str(), "<details open></details>");
 }
 
 #[test]
@@ -44,6 +44,20 @@ fn nested_regular_tags_with_regular_attribute() {
     assert_eq!(output.as_str(), r#"<div id="a"><div id="b"></div></div>"#);
 }
 
+#[test]
+fn dashed_and_raw_attribute_names() {
+    let output: String = html_format! { <input r#type={"text"} data-user-id={"7"} /> };
+
+    assert_eq!(output.as_str(), r#"<input type="text" data-user-id="7" />"#);
+}
+
+#[test]
+fn literal_children() {
+    let output: String = html_format! { <p>{'a'}{b'b'}{1.5}{true}</p> };
+
+    assert_eq!(output.as_str(), "<p>a981.5true</p>");
+}
+
 #[test]
 fn regular_tags_with_regular_attribute_with_raw_child() {
     let output: String = html_format! { <div id={"a"}>{"Hello"}</div> };
@@ -121,3 +135,133 @@ fn nested_tags_with_variable_input() {
 
     assert_eq!(output.as_str(), "<div><h1>Hola</h1><hr /></div>");
 }
+
+#[test]
+fn unbraced_literal_attributes() {
+    let output: String = html_format! {
+      <input id="name" maxlength=32 tabindex=-1 required=true hidden=false />
+    };
+
+    assert_eq!(
+        output.as_str(),
+        r#"<input id="name" maxlength="32" tabindex="-1" required />"#
+    );
+}
+
+#[test]
+fn unbraced_literal_attributes_are_escaped() {
+    let output: String = html_format! { <p title="a\"b<c" lang='&'>"x"</p> };
+
+    assert_eq!(
+        output.as_str(),
+        r#"<p title="a&quot;b&lt;c" lang="&amp;">x</p>"#
+    );
+}
+
+#[test]
+fn braced_literal_attributes() {
+    let output: String = html_format! {
+      <input id={"name"} required={true} hidden={false} step={1.50} />
+    };
+
+    assert_eq!(
+        output.as_str(),
+        r#"<input id="name" required="true" hidden="false" step="1.50" />"#
+    );
+}
+
+#[test]
+fn bare_text_children() {
+    let name = "Gal";
+
+    let output: String = html_format! { <p>"Hello, " {name} f "! {name} again"</p> };
+
+    assert_eq!(output.as_str(), "<p>Hello, Gal! Gal again</p>");
+}
+
+#[test]
+fn bare_text_children_are_escaped() {
+    let name = "Gal";
+
+    let output: String = html_format! { <p>"a < b & c" f " <{name}>"</p> };
+
+    assert_eq!(output.as_str(), "<p>a &lt; b &amp; c &lt;Gal&gt;</p>");
+}
+
+#[test]
+fn literal_comment_children() {
+    let output: String = html_format! { <div><!-- "[if IE]><p>Old</p><![endif]" -->{"c"}</div> };
+
+    assert_eq!(
+        output.as_str(),
+        "<div><!--[if IE]><p>Old</p><![endif]-->c</div>"
+    );
+}
+
+#[test]
+fn raw_text_children() {
+    let output: String = html_format! {
+        <div><script>"if (a && b) { end('</script>'); }"</script><style>{"p > a { color: red }"}</style></div>
+    };
+
+    assert_eq!(
+        output.as_str(),
+        r"<div><script>if (a && b) { end('<\/script>'); }</script><style>p > a { color: red }</style></div>"
+    );
+}
+
+#[test]
+fn raw_text_children_with_values() {
+    let closing = "</scr";
+
+    let output: String = html_format! { <script>{closing}"ipt><b>"</script> };
+
+    assert_eq!(output.as_str(), r"<script><\/script><b></script>");
+}
+
+#[test]
+fn minified() {
+    let output: String = html_format! {
+        #![minify]
+        <div class="menu">
+            <ul>
+                <li><a href="/">"Home"</a></li>
+                <li class="a b">"  Docs  "<input type="checkbox" checked=true /></li>
+            </ul>
+            <p>"One"</p>
+            "\n  "
+            <p>"Two"</p>
+            <pre>"  a  b  "</pre>
+            <span><p>"Three"</p></span>
+            <section><p>"Four"</p></section>
+            <script>"if (a  &&  b) {}"</script>
+        </div>
+    };
+
+    assert_eq!(
+        output.as_str(),
+        concat!(
+            "<div class=menu><ul><li><a href=/>Home</a>",
+            r#"<li class="a b">  Docs  <input type=checkbox checked></ul>"#,
+            "<p>One</p> <p>Two<pre>  a  b  </pre>",
+            "<span><p>Three</p></span><section><p>Four</section>",
+            "<script>if (a  &&  b) {}</script></div>"
+        )
+    );
+}
+
+#[test]
+fn minified_with_values() {
+    let id = "x y";
+    let name = "Gal";
+
+    let output: String = html_format! {
+        #![minify]
+        <ul><li id={id}>"Hello, " {name}</li><li>"Bye"</li></ul>
+    };
+
+    assert_eq!(
+        output.as_str(),
+        r#"<ul><li id="x y">Hello, Gal<li>Bye</ul>"#
+    );
+}
@@ -0,0 +1,209 @@
+//! The rules of HTML's syntax that rendering and minifying rely on
+//!
+//! `html-codegen` minifies with them when rendering, and
+//! `html-codegen-macros` when minifying the static chunks of `html_format!`
+//! at compile time, so both crates minify the same way.
+
+use std::fmt::{Result, Write};
+
+/// A tag of the output, by the name of its element
+pub enum Tag<'a> {
+    Start(&'a str),
+    End(&'a str),
+    /// A comment or doctype
+    Other,
+}
+
+impl<'a> Tag<'a> {
+    /// Reads a tag from its `<` to its `>`
+    pub fn parse(tag: &'a str) -> Self {
+        let (end, rest) = match tag[1..].strip_prefix('/') {
+            Some(rest) => (true, rest),
+            None => (false, &tag[1..]),
+        };
+
+        let name_len = rest
+            .find(|c: char| c.is_ascii_whitespace() || c == '/' || c == '>')
+            .unwrap_or(rest.len());
+        let name = &rest[..name_len];
+
+        match name.chars().next() {
+            Some(first) if first.is_ascii_alphabetic() => {
+                if end {
+                    Self::End(name)
+                } else {
+                    Self::Start(name)
+                }
+            }
+            _ => Self::Other,
+        }
+    }
+}
+
+/// Writes a start tag without the quotes its attribute values don't need,
+/// and without the `/` of a void element
+pub fn write_start_tag<W: Write>(tag: &str, name: &str, writer: &mut W) -> Result {
+    let tag = match tag.strip_suffix("/>") {
+        Some(tag) if is_void_element(name) => tag.trim_end(),
+        // a `/` after an unquoted value would be part of it
+        Some(_) => return writer.write_str(tag),
+        None => &tag[..tag.len() - 1],
+    };
+
+    let mut rest = tag;
+    while let Some(start) = rest.find("=\"") {
+        writer.write_str(&rest[..=start])?;
+        let after_quote = &rest[start + 2..];
+
+        let Some(len) = after_quote.find('"') else {
+            rest = &rest[start + 1..];
+            break;
+        };
+
+        let value = &after_quote[..len];
+        if needs_quotes(value) {
+            write!(writer, "\"{value}\"")?;
+        } else {
+            writer.write_str(value)?;
+        }
+
+        rest = &after_quote[len + 1..];
+    }
+
+    writer.write_str(rest)?;
+    writer.write_char('>')
+}
+
+/// Whether an attribute value can't be written without quotes
+fn needs_quotes(value: &str) -> bool {
+    value.is_empty()
+        || value.contains(|c: char| {
+            c.is_ascii_whitespace() || matches!(c, '"' | '\'' | '=' | '<' | '>' | '`')
+        })
+}
+
+/// Whether `name` is an element with no contents and no end tag, like `<br>`
+pub fn is_void_element(name: &str) -> bool {
+    matches!(
+        name,
+        "area"
+            | "base"
+            | "br"
+            | "col"
+            | "embed"
+            | "hr"
+            | "img"
+            | "input"
+            | "link"
+            | "meta"
+            | "param"
+            | "source"
+            | "track"
+            | "wbr"
+    )
+}
+
+/// Whether the whitespace in `name` is shown as it is
+pub fn is_preformatted_element(name: &str) -> bool {
+    matches!(name, "pre" | "textarea")
+}
+
+/// Whether HTML lets a page leave out the end tag of `name` in some cases
+pub fn has_optional_end_tag(name: &str) -> bool {
+    matches!(
+        name,
+        "li" | "dt"
+            | "dd"
+            | "p"
+            | "rt"
+            | "rp"
+            | "optgroup"
+            | "option"
+            | "thead"
+            | "tbody"
+            | "tfoot"
+            | "tr"
+            | "td"
+            | "th"
+    )
+}
+
+/// Whether the end tag of `omitted` can be left out when it's followed by
+/// the start tag of `next`
+pub fn may_omit_before(omitted: &str, next: &str) -> bool {
+    match omitted {
+        "li" => next == "li",
+        "dt" | "dd" => matches!(next, "dt" | "dd"),
+        "p" => matches!(next, "hr" | "p") || closes_p(next),
+        "rt" | "rp" => matches!(next, "rt" | "rp"),
+        "optgroup" => matches!(next, "optgroup" | "hr"),
+        "option" => matches!(next, "option" | "optgroup" | "hr"),
+        "thead" | "tbody" => matches!(next, "tbody" | "tfoot"),
+        "tr" => next == "tr",
+        "td" | "th" => matches!(next, "td" | "th"),
+        _ => false,
+    }
+}
+
+/// Whether the end tag of `omitted` can be left out when it's followed by
+/// the end tag of its parent, `parent`
+pub fn may_omit_before_parent_end(omitted: &str, parent: &str) -> bool {
+    match omitted {
+        "dt" | "thead" => false,
+        // only end tags that close an open `<p>` when they're parsed, which
+        // leaves out inline elements and custom elements
+        "p" => {
+            closes_p(parent)
+                || matches!(
+                    parent,
+                    "li" | "dd"
+                        | "dt"
+                        | "td"
+                        | "th"
+                        | "caption"
+                        | "button"
+                        | "template"
+                        | "body"
+                        | "html"
+                )
+        }
+        _ => true,
+    }
+}
+
+/// The block elements whose start and end tags close an open `<p>`
+fn closes_p(name: &str) -> bool {
+    matches!(
+        name,
+        "address"
+            | "article"
+            | "aside"
+            | "blockquote"
+            | "details"
+            | "dialog"
+            | "div"
+            | "dl"
+            | "fieldset"
+            | "figcaption"
+            | "figure"
+            | "footer"
+            | "form"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+            | "header"
+            | "hgroup"
+            | "main"
+            | "menu"
+            | "nav"
+            | "ol"
+            | "pre"
+            | "search"
+            | "section"
+            | "table"
+            | "ul"
+    )
+}
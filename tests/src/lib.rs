@@ -4,6 +4,13 @@ fn ui() {
     t.compile_fail("ui/fail/*.rs");
 }
 
+#[cfg(feature = "strict-html")]
+#[test]
+fn strict_html_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("ui/strict/*.rs");
+}
+
 #[test]
 fn maintains_valid_urls_with_raw_attribute() {
     use html_codegen::{html, raw};
@@ -17,6 +24,7 @@ fn maintains_valid_urls_with_raw_attribute() {
     );
 }
 
+#[cfg(not(feature = "strict-html"))]
 #[test]
 fn works_with_dashes() {
     use pretty_assertions::assert_eq;
@@ -40,6 +48,7 @@ fn works_with_raw() {
     assert_eq!(actual, "<div><Hello /></div>");
 }
 
+#[cfg(not(feature = "strict-html"))]
 #[test]
 fn works_with_htmx_ident() {
     use pretty_assertions::assert_eq;
@@ -238,85 +247,1547 @@ fn vec() {
     )
 }
 
-mod kaki {
-    // A simple HTML 5 doctype declaration
-    use html_codegen::html::HTML5Doctype;
-    use html_codegen::{
-        // A macro to create components
-        component,
-        // A macro to compose components in JSX fashion
-        rsx,
-        // A trait for custom components
-        Render,
-    };
+#[test]
+fn if_else_children() {
+    use html_codegen::{component, html, rsx};
+    use pretty_assertions::assert_eq;
 
-    // This can be any layout we want
     #[component]
-    fn Page<'a, Children: Render>(title: &'a str, children: Children) {
-        rsx! {
-          <>
-            <HTML5Doctype />
-            <html>
-              <head><title>{title}</title></head>
-              <body hx-boost={"true"} hx-swap={"innerHTML"} checked={false}>
-                {children}
-              </body>
-            </html>
-          </>
+    fn Badge<'label>(label: &'label str) {
+        rsx! { <span class={"badge"}>{label}</span> }
+    }
+
+    let render = |count: u32| {
+        html! {
+            <p>
+                if count == 0 {
+                    {"Nothing here"}
+                } else if count == 1 {
+                    <Badge label={"one"} />
+                } else {
+                    <strong>{count}</strong>
+                    {" items"}
+                }
+            </p>
+        }
+        .unwrap()
+    };
+
+    assert_eq!(render(0), "<p>Nothing here</p>");
+    assert_eq!(render(1), r#"<p><span class="badge">one</span></p>"#);
+    assert_eq!(render(7), "<p><strong>7</strong> items</p>");
+}
+
+#[test]
+fn if_without_else_children() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let render = |admin: bool| {
+        html! {
+            <ul>
+                <li>{"Home"}</li>
+                if admin {
+                    <li>{"Admin"}</li>
+                }
+            </ul>
+        }
+        .unwrap()
+    };
+
+    assert_eq!(render(true), "<ul><li>Home</li><li>Admin</li></ul>");
+    assert_eq!(render(false), "<ul><li>Home</li></ul>");
+}
+
+#[test]
+fn if_let_children() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let render = |user: Option<&str>| {
+        html! {
+            <div>
+                if let Some(name) = user {
+                    <a href={"/profile"}>{name}</a>
+                } else {
+                }
+            </div>
         }
+        .unwrap()
+    };
+
+    assert_eq!(
+        render(Some("Gal")),
+        r#"<div><a href="/profile">Gal</a></div>"#
+    );
+    assert_eq!(render(None), "<div></div>");
+}
+
+#[test]
+fn for_children() {
+    use html_codegen::{component, html, rsx};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Item<'text>(text: &'text str) {
+        rsx! { <li>{text}</li> }
     }
 
-    #[test]
-    fn test() {
-        use pretty_assertions::assert_eq;
-        let actual = html_codegen::html! {
-          <Page title={"Home"}>
-            {format!("Welcome, {}", "Gal")}
-          </Page>
+    let list = vec!["Mouse", "Rat", "Hamster"];
+
+    assert_eq!(
+        html! {
+            <ul>
+                for text in &list {
+                    <Item text={text} />
+                }
+            </ul>
         }
-        .unwrap();
-        let expected = concat!(
-            "<!DOCTYPE html>",
-            "<html>",
-            "<head><title>Home</title></head>",
-            r#"<body hx-boost="true" hx-swap="innerHTML">"#,
-            "Welcome, Gal",
-            "</body>",
-            "</html>"
-        );
-        assert_eq!(actual, expected);
+        .unwrap(),
+        "<ul><li>Mouse</li><li>Rat</li><li>Hamster</li></ul>"
+    );
+
+    assert_eq!(
+        html! {
+            <ul>
+                for text in list.iter().filter(|text| text.starts_with('R')) {
+                    <li>{*text}</li>
+                }
+            </ul>
+        }
+        .unwrap(),
+        "<ul><li>Rat</li></ul>"
+    );
+}
+
+#[test]
+fn for_children_with_index() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let rows = [("Ada", 36), ("Grace", 85)];
+
+    assert_eq!(
+        html! {
+            <table>
+                for index, (name, age) in rows {
+                    <tr>
+                        <td>{index}</td>
+                        <td>{name}</td>
+                        if age > 40 {
+                            <td>{"senior"}</td>
+                        }
+                    </tr>
+                }
+            </table>
+        }
+        .unwrap(),
+        concat!(
+            "<table>",
+            "<tr><td>0</td><td>Ada</td></tr>",
+            "<tr><td>1</td><td>Grace</td><td>senior</td></tr>",
+            "</table>"
+        )
+    );
+}
+
+#[test]
+fn for_children_empty_iterator() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        html! {
+            <ul>
+                for number in 0..0 {
+                    <li>{number}</li>
+                }
+            </ul>
+        }
+        .unwrap(),
+        "<ul></ul>"
+    );
+}
+
+#[test]
+fn match_children() {
+    use html_codegen::{component, html, rsx};
+    use pretty_assertions::assert_eq;
+
+    enum Role {
+        Admin,
+        Editor { sections: u8 },
+        Viewer,
+        Banned(&'static str),
     }
 
-    #[test]
-    fn externals_test() {
-        use crate::other::ExternalPage;
-        use pretty_assertions::assert_eq;
+    #[component]
+    fn Pill<'text>(text: &'text str) {
+        rsx! { <span class={"pill"}>{text}</span> }
+    }
 
-        let actual = html_codegen::html! {
-          <ExternalPage title={"Home"} subtitle={"Foo"}>
-            {format!("Welcome, {}", "Gal")}
-          </ExternalPage>
+    let render = |role: Role| {
+        html! {
+            <div>
+                match role {
+                    Role::Admin => <Pill text={"admin"} />,
+                    Role::Editor { sections: 0 } | Role::Viewer => {
+                        {"read only"}
+                    }
+                    Role::Editor { sections } => {
+                        <b>{sections}</b>
+                        {" sections"}
+                    }
+                    Role::Banned(reason) if reason.is_empty() => <i>{"banned"}</i>,
+                    Role::Banned(reason) => <i title={reason}>{"banned"}</i>
+                }
+            </div>
         }
-        .unwrap();
+        .unwrap()
+    };
 
-        let expected = concat!(
-            "<!DOCTYPE html>",
-            "<html>",
-            "<head><title>Home</title></head>",
-            "<body>",
-            "<h1>Foo</h1>",
-            "Welcome, Gal",
-            "</body>",
-            "</html>"
-        );
-        assert_eq!(actual, expected);
+    assert_eq!(
+        render(Role::Admin),
+        r#"<div><span class="pill">admin</span></div>"#
+    );
+    assert_eq!(render(Role::Viewer), "<div>read only</div>");
+    assert_eq!(render(Role::Editor { sections: 0 }), "<div>read only</div>");
+    assert_eq!(
+        render(Role::Editor { sections: 3 }),
+        "<div><b>3</b> sections</div>"
+    );
+    assert_eq!(render(Role::Banned("")), "<div><i>banned</i></div>");
+    assert_eq!(
+        render(Role::Banned("spam")),
+        r#"<div><i title="spam">banned</i></div>"#
+    );
+}
+
+#[test]
+fn match_children_single_arm() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let pair = (1, 2);
+
+    assert_eq!(
+        html! {
+            <p>
+                match pair {
+                    (left, right) => {
+                        {left}{"+"}{right}
+                    }
+                }
+            </p>
+        }
+        .unwrap(),
+        "<p>1+2</p>"
+    );
+}
+
+#[test]
+fn spread_attributes() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let extra_attrs = vec![("id", "query"), ("type", "search")];
+    let required = [("required", true)];
+
+    assert_eq!(
+        html! {
+            <input {..extra_attrs} type={"text"} {..required} placeholder={"Search"} />
+        }
+        .unwrap(),
+        r#"<input id="query" type="text" required placeholder="Search"/>"#
+    );
+}
+
+#[test]
+fn spread_attributes_override_literal_attributes() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let overrides = [("class", "danger")];
+
+    assert_eq!(
+        html! { <button class={"primary"} type={"submit"} {..overrides}>{"Delete"}</button> }
+            .unwrap(),
+        r#"<button type="submit" class="danger">Delete</button>"#
+    );
+}
+
+#[test]
+fn spread_ordered_hash_map() {
+    use html_codegen::{html, OrderedHashMap, ToAttribute, AV};
+    use pretty_assertions::assert_eq;
+
+    let mut attrs = OrderedHashMap::<&str, AV>::new();
+    attrs.insert("href", ToAttribute::from_value("/home"));
+    attrs.insert("hidden", ToAttribute::from_value(false));
+
+    assert_eq!(
+        html! { <a {..attrs}>{"Home"}</a> }.unwrap(),
+        r#"<a href="/home">Home</a>"#
+    );
+}
+
+#[test]
+fn spread_custom_component_props() {
+    use html_codegen::{component, html, rsx, Render};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Badge<'a, Children: Render>(color: &'a str, label: &'a str, children: Children) {
+        rsx! { <span class={color} title={label}>{children}</span> }
     }
+
+    let defaults = || Badge {
+        color: "gray",
+        label: "badge",
+        children: Some("?"),
+    };
+
+    assert_eq!(
+        html! { <Badge {..defaults()} /> }.unwrap(),
+        r#"<span class="gray" title="badge">?</span>"#
+    );
+    assert_eq!(
+        html! { <Badge color={"red"} {..defaults()}>{"!"}</Badge> }.unwrap(),
+        r#"<span class="red" title="badge">!</span>"#
+    );
 }
 
-/// ## Other
-///
-/// Module for testing component visibility when imported from other modules.
+#[test]
+fn class_directives() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let render = |is_active: bool, size: Option<&str>| {
+        html! {
+            <button
+                type={"button"}
+                class:active={is_active}
+                class={[Some("btn btn-primary"), size]}
+                class:btn={true}
+            >
+                {"Save"}
+            </button>
+        }
+        .unwrap()
+    };
+
+    assert_eq!(
+        render(true, Some("btn-lg")),
+        r#"<button type="button" class="active btn btn-primary btn-lg">Save</button>"#
+    );
+    assert_eq!(
+        render(false, None),
+        r#"<button type="button" class="btn btn-primary">Save</button>"#
+    );
+}
+
+#[test]
+fn class_directives_without_classes() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let selected = false;
+
+    assert_eq!(
+        html! { <li class:selected>{"Item"}</li> }.unwrap(),
+        "<li>Item</li>"
+    );
+}
+
+#[test]
+fn class_list_attribute() {
+    use html_codegen::{html, ClassList};
+    use pretty_assertions::assert_eq;
 
+    let tags = vec![String::from("new"), String::from("sale  featured")];
+    let classes: ClassList = tags.into_iter().collect();
+
+    assert_eq!(
+        html! { <div class={classes.with(("new", true))} /> }.unwrap(),
+        r#"<div class="new sale featured"></div>"#
+    );
+}
+
+#[test]
+fn style_directives() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let render = |color: &str, background: Option<String>| {
+        html! {
+            <div
+                style:color={color}
+                id={"banner"}
+                style={"display: flex; color: black"}
+                style:background-color={background}
+            />
+        }
+        .unwrap()
+    };
+
+    assert_eq!(
+        render("white", Some(String::from("navy"))),
+        r#"<div style="color: black; display: flex; background-color: navy" id="banner"></div>"#
+    );
+    assert_eq!(
+        render("</style><script>", None),
+        r#"<div style="color: black; display: flex" id="banner"></div>"#
+    );
+}
+
+#[test]
+fn style_escapes_values() {
+    use html_codegen::{html, Style};
+    use pretty_assertions::assert_eq;
+
+    let user_input = "red;}\n</div>";
+    let style = Style::new().with("color", user_input);
+
+    assert_eq!(
+        html! { <span style={style} /> }.unwrap(),
+        r#"<span style="color: red\3b \7d \a &lt;/div&gt;"></span>"#
+    );
+
+    // A comment or a string would swallow the declarations after it
+    assert_eq!(
+        html! { <b style:color={"red /*"} style:background={"blue"}>"!"</b> }.unwrap(),
+        r#"<b style="color: red \2f *; background: blue">!</b>"#
+    );
+    assert_eq!(
+        html! { <b style:font-family={"'x"} style:content={r#""y"#} style:top={"0"}>"!"</b> }
+            .unwrap(),
+        r#"<b style="font-family: \27 x; content: \22 y; top: 0">!</b>"#
+    );
+}
+
+#[test]
+fn style_keeps_strings_and_urls_of_declaration_blocks() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let color = "red";
+
+    assert_eq!(
+        html! {
+            <div
+                style=r#"background: url("data:image/png;base64,AAA=") no-repeat; content: ";"; color: black"#
+                style:color={color}
+            />
+        }
+        .unwrap(),
+        r#"<div style="background: url(&quot;data:image/png;base64,AAA=&quot;) no-repeat; content: &quot;;&quot;; color: red"></div>"#
+    );
+    assert_eq!(
+        html! { <b style=r#"mask: url(icon.svg#a;b); quotes: '\'' ';'"# style:top="0">"!"</b> }
+            .unwrap(),
+        r#"<b style="mask: url(icon.svg#a;b); quotes: &apos;\&apos;&apos; &apos;;&apos;; top: 0">!</b>"#
+    );
+}
+
+#[test]
+fn style_without_declarations() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let color: Option<&str> = None;
+
+    assert_eq!(html! { <b style:color>{"!"}</b> }.unwrap(), "<b>!</b>");
+}
+
+#[test]
+fn unbraced_literal_attributes() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    assert_eq!(
+        html! {
+            <button type="submit" title="Say \"hi\" & <go>" tabindex=-1 data-step=1.5 autofocus=true disabled=false>
+                {"Go"}
+            </button>
+        }
+        .unwrap(),
+        r#"<button type="submit" title="Say &quot;hi&quot; &amp; &lt;go&gt;" tabindex="-1" data-step="1.5" autofocus>Go</button>"#
+    );
+}
+
+#[test]
+fn braced_literal_attributes() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    // Braced values render at runtime, like any other expression
+    assert_eq!(
+        html! { <input step={1.50} min={1e3} required={true} hidden={false} title={"<"} /> }
+            .unwrap(),
+        r#"<input step="1.5" min="1000" required title="&lt;"/>"#
+    );
+}
+
+#[test]
+fn unbraced_literal_attributes_on_components() {
+    use html_codegen::{component, html, rsx};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Counter<'a>(label: &'a str, count: i32, visible: bool) {
+        rsx! {
+            <span hidden={!visible}>{label}{": "}{count}</span>
+        }
+    }
+
+    assert_eq!(
+        html! { <Counter label="Stars" count=-3 visible=true /> }.unwrap(),
+        "<span>Stars: -3</span>"
+    );
+}
+
+#[test]
+fn literal_attribute_overrides_spread() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let attrs = [("disabled", true)];
+
+    assert_eq!(
+        html! { <input {..attrs} disabled=false /> }.unwrap(),
+        "<input/>"
+    );
+}
+
+#[test]
+fn text_children() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let name = "Gal";
+
+    assert_eq!(
+        html! {
+            <p>
+                "Hello, " {name}
+                "!"
+                " Fish & chips <3 "
+            </p>
+        }
+        .unwrap(),
+        "<p>Hello, Gal! Fish &amp; chips &lt;3 </p>"
+    );
+}
+
+#[test]
+fn format_text_children() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let user = ("<script>", 2);
+    let items = ["a", "b"];
+
+    assert_eq!(
+        html! {
+            <p>f "Hi {user.0}, {{you}} have {user.1 + 1} > {items.len()} messages"</p>
+        }
+        .unwrap(),
+        "<p>Hi &lt;script&gt;, {you} have 3 &gt; 2 messages</p>"
+    );
+}
+
+#[test]
+fn text_children_in_control_flow() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let count = 1;
+
+    assert_eq!(
+        html! {
+            <span>
+                if count == 1 { "1 item" } else { f "{count} items" }
+            </span>
+        }
+        .unwrap(),
+        "<span>1 item</span>"
+    );
+}
+
+#[test]
+fn comment_children() {
+    use html_codegen::html;
+    use pretty_assertions::assert_eq;
+
+    let marker = String::from("end of <main>");
+
+    assert_eq!(
+        html! {
+            <main>
+                <!-- "[if IE]><p>Upgrade your browser</p><![endif]" -->
+                "Content"
+                <!-- {marker} -->
+            </main>
+        }
+        .unwrap(),
+        "<main><!--[if IE]><p>Upgrade your browser</p><![endif]-->Content<!--end of <main>--></main>"
+    );
+}
+
+#[test]
+fn comment_cannot_be_closed_early() {
+    use html_codegen::{html, Comment};
+    use pretty_assertions::assert_eq;
+
+    let text = "--><script>alert(1)</script><!-- --!> -";
+
+    assert_eq!(
+        html! { <div><Comment text={text} /></div> }.unwrap(),
+        "<div><!--- -><script>alert(1)</script><!- - - -!> - --></div>"
+    );
+    assert_eq!(
+        html! { <div><Comment text=">" /><Comment text="->" /><Comment text="<!-" /></div> }
+            .unwrap(),
+        "<div><!-- >--><!-- ->--><!--<!- --></div>"
+    );
+}
+
+#[test]
+fn named_slots() {
+    use html_codegen::{component, html, rsx, Render};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Card<'a, Header: Render, Footer: Render, Children: Render>(
+        title: &'a str,
+        header: Header,
+        footer: Footer,
+        children: Children,
+    ) {
+        rsx! {
+            <article title={title}>
+                <header>{header}</header>
+                <div>{children}</div>
+                <footer>{footer}</footer>
+            </article>
+        }
+    }
+
+    let user = "Gal";
+
+    assert_eq!(
+        html! {
+            <Card title="Profile">
+                <slot:footer>"Joined " <b>"2024"</b></slot:footer>
+                <p>{user}</p>
+                <slot:header>
+                    <h2>"Profile"</h2>
+                </slot:header>
+                <p>"Bio"</p>
+            </Card>
+        }
+        .unwrap(),
+        concat!(
+            r#"<article title="Profile">"#,
+            "<header><h2>Profile</h2></header>",
+            "<div><p>Gal</p><p>Bio</p></div>",
+            "<footer>Joined <b>2024</b></footer>",
+            "</article>"
+        )
+    );
+}
+
+#[test]
+fn named_slots_without_children() {
+    use html_codegen::{component, html, rsx, Render};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Dialog<Actions: Render>(actions: Actions) {
+        rsx! { <dialog><menu>{actions}</menu></dialog> }
+    }
+
+    assert_eq!(
+        html! {
+            <Dialog>
+                <slot:actions>
+                    for label in ["Ok", "Cancel"] {
+                        <button>{label}</button>
+                    }
+                </slot:actions>
+            </Dialog>
+        }
+        .unwrap(),
+        "<dialog><menu><button>Ok</button><button>Cancel</button></menu></dialog>"
+    );
+}
+
+#[test]
+fn optional_props() {
+    use html_codegen::enumerated::Unchecked;
+    use html_codegen::{component, html, rsx, Render};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Debug, Default, PartialEq)]
+    enum Size {
+        Small,
+        #[default]
+        Medium,
+    }
+
+    #[component]
+    fn Button<'a, Children: Render>(
+        #[prop(default)] size: Size,
+        #[prop(default = String::from("button"))] kind: String,
+        #[prop(default = 0)] tab_index: i32,
+        id: Option<&'a str>,
+        children: Children,
+    ) {
+        let class = match size {
+            Size::Small => "btn btn-sm",
+            Size::Medium => "btn",
+        };
+
+        rsx! {
+            <button id={id} class={class} type={Unchecked(kind)} tabindex={tab_index}>{children}</button>
+        }
+    }
+
+    assert_eq!(
+        html! { <Button>"Save"</Button> }.unwrap(),
+        r#"<button class="btn" type="button" tabindex="0">Save</button>"#
+    );
+    assert_eq!(
+        html! {
+            <Button id={Some("go")} size={Size::Small} kind={String::from("submit")} tab_index=-1>
+                "Go"
+            </Button>
+        }
+        .unwrap(),
+        r#"<button id="go" class="btn btn-sm" type="submit" tabindex="-1">Go</button>"#
+    );
+}
+
+#[test]
+fn props_builder() {
+    use html_codegen::enumerated::Unchecked;
+    use html_codegen::{component, Render};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Link<'a>(href: &'a str, #[prop(default = "_self")] target: &'a str) {
+        html_codegen::rsx! { <a href={href} target={Unchecked(target)} /> }
+    }
+
+    let link = Link::builder().target("_blank").href("/docs").build();
+
+    assert_eq!(link.target, "_blank");
+    assert_eq!(
+        link.render().unwrap(),
+        r#"<a href="/docs" target="_blank"></a>"#
+    );
+}
+
+#[test]
+fn components_without_props_builder() {
+    use html_codegen::{html, rsx, Render};
+    use pretty_assertions::assert_eq;
+
+    struct Label<T: std::fmt::Display> {
+        text: T,
+        hidden: bool,
+    }
+
+    impl<T: std::fmt::Display> Render for Label<T> {
+        fn render_into<W: std::fmt::Write>(self, writer: &mut W) -> std::fmt::Result {
+            let text = self.text.to_string();
+            rsx! { <label hidden={self.hidden}>{text}</label> }.render_into(writer)
+        }
+    }
+
+    // Not a props builder, so `rsx!` still creates labels with a struct literal
+    impl<T: std::fmt::Display> Label<T> {
+        #[allow(dead_code)]
+        fn builder(text: T) -> Self {
+            Self {
+                text,
+                hidden: false,
+            }
+        }
+    }
+
+    let name = "Name";
+
+    assert_eq!(
+        html! {
+            <>
+                <Label text={name.to_uppercase().as_str()} hidden={false} />
+                <Label hidden={true} text={42} />
+                <html_codegen::html::HTML5Doctype />
+            </>
+        }
+        .unwrap(),
+        r#"<label>NAME</label><label hidden>42</label><!DOCTYPE html>"#
+    );
+}
+
+#[test]
+fn derived_props() {
+    use html_codegen::{html, rsx, Props, Render};
+    use pretty_assertions::assert_eq;
+
+    #[derive(Props)]
+    struct Tag<T: std::fmt::Display> {
+        label: T,
+        #[prop(default)]
+        count: Option<u32>,
+    }
+
+    impl<T: std::fmt::Display> Render for Tag<T> {
+        fn render_into<W: std::fmt::Write>(self, writer: &mut W) -> std::fmt::Result {
+            let label = self.label.to_string();
+            let count = self.count.map(|count| count.to_string());
+            rsx! { <span data-count={count}>{label}</span> }.render_into(writer)
+        }
+    }
+
+    #[derive(Props)]
+    struct Divider;
+
+    impl Render for Divider {
+        fn render_into<W: std::fmt::Write>(self, writer: &mut W) -> std::fmt::Result {
+            rsx! { <hr /> }.render_into(writer)
+        }
+    }
+
+    assert_eq!(
+        html! {
+            <>
+                <Tag label="new" count={Some(3)} />
+                <Divider />
+                <Tag label={7} />
+            </>
+        }
+        .unwrap(),
+        r#"<span data-count="3">new</span><hr/><span>7</span>"#
+    );
+}
+
+#[test]
+fn into_props() {
+    use html_codegen::{component, html, rsx};
+    use pretty_assertions::assert_eq;
+    use std::borrow::Cow;
+
+    struct UserId(u32);
+
+    impl From<UserId> for String {
+        fn from(id: UserId) -> Self {
+            format!("user-{}", id.0)
+        }
+    }
+
+    #[component]
+    fn Profile<'a>(
+        #[prop(into)] id: String,
+        #[prop(into)] name: Cow<'a, str>,
+        #[prop(into, default = 0u64)] followers: u64,
+    ) {
+        rsx! { <p id={id}>{name}{" · "}{followers}</p> }
+    }
+
+    let name = String::from("Gal");
+
+    assert_eq!(
+        html! { <Profile id={UserId(7)} name={name.as_str()} followers={12u32} /> }.unwrap(),
+        r#"<p id="user-7">Gal · 12</p>"#
+    );
+    assert_eq!(
+        html! { <Profile id="anonymous" name={name.clone()} /> }.unwrap(),
+        r#"<p id="anonymous">Gal · 0</p>"#
+    );
+}
+
+#[test]
+fn components_with_a_return_type() {
+    use html_codegen::{component, html, rsx, SimpleElement};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Bold<'a>(text: &'a str) -> SimpleElement<'static, &'a str> {
+        rsx! { <b>{text}</b> }
+    }
+
+    #[component]
+    fn Italic<'a>(text: &'a str) -> impl html_codegen::Render + 'a {
+        rsx! { <i>{text}</i> }
+    }
+
+    assert_eq!(
+        html! { <p><Bold text="a" /><Italic text="b" /></p> }.unwrap(),
+        "<p><b>a</b><i>b</i></p>"
+    );
+}
+
+#[test]
+fn fallible_components() {
+    use html_codegen::{component, html, rsx, Render, RenderError};
+    use pretty_assertions::assert_eq;
+    use std::error::Error;
+
+    #[component]
+    fn Port<'a>(value: &'a str) -> Result<_, std::num::ParseIntError> {
+        let port: u16 = value.parse()?;
+        Ok(rsx! { <code>{port}</code> })
+    }
+
+    #[component]
+    fn Server<'a>(host: &'a str, port: &'a str) -> Result<_, &'static str> {
+        if host.is_empty() {
+            return Err("missing host");
+        }
+        Ok(rsx! { <li>{host}{":"}<Port value={port} /></li> })
+    }
+
+    struct Forbidden;
+
+    impl Render for Forbidden {
+        fn render_into<W: std::fmt::Write>(self, _writer: &mut W) -> std::fmt::Result {
+            Err(RenderError::component("Forbidden", "not allowed").raise())
+        }
+    }
+
+    assert_eq!(
+        html! { <Server host="localhost" port="8080" /> }.unwrap(),
+        "<li>localhost:<code>8080</code></li>"
+    );
+
+    let error = html! { <ul><Server host="" port="8080" /></ul> }.unwrap_err();
+    assert_eq!(error.component_name(), Some("Server"));
+    assert_eq!(error.to_string(), "failed to render `Server`: missing host");
+
+    let error = html! { <ul><Server host="localhost" port="http" /></ul> }.unwrap_err();
+    assert_eq!(error.component_name(), Some("Port"));
+    assert!(error
+        .source()
+        .is_some_and(|source| source.is::<std::num::ParseIntError>()));
+
+    let error = html! { <p>{Forbidden}</p> }.unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "failed to render `Forbidden`: not allowed"
+    );
+
+    let mut writer = String::new();
+    assert!(rsx! { <p>{Forbidden}</p> }
+        .render_into(&mut writer)
+        .is_err());
+    assert_eq!(writer, "<p>");
+}
+
+#[test]
+fn provided_context() {
+    use html_codegen::{component, html, rsx, use_context, Provide};
+    use pretty_assertions::assert_eq;
+    use std::rc::Rc;
+
+    #[derive(Clone)]
+    struct CsrfToken(Rc<str>);
+
+    #[derive(Clone, Copy)]
+    struct Theme(&'static str);
+
+    #[component]
+    fn CsrfField() {
+        let token = use_context::<CsrfToken>().map(|token| token.0.to_string());
+        rsx! { <input type="hidden" name="csrf" value={token} /> }
+    }
+
+    #[component]
+    fn Themed() {
+        let theme = use_context::<Theme>().map_or("light", |theme| theme.0);
+        rsx! { <div class={theme} /> }
+    }
+
+    #[component]
+    fn Fieldset<Children: html_codegen::Render>(children: Children) {
+        rsx! { <fieldset>{children}</fieldset> }
+    }
+
+    #[component]
+    fn Form() {
+        rsx! { <form><Fieldset><CsrfField /><Themed /></Fieldset></form> }
+    }
+
+    let result = html! {
+        <Provide value={CsrfToken("abc".into())}>
+            <Provide value={Theme("dark")}>
+                <Form />
+                <Provide value={Theme("contrast")}>
+                    <Themed />
+                </Provide>
+                <Themed />
+            </Provide>
+            <Themed />
+        </Provide>
+    }
+    .unwrap();
+
+    assert_eq!(
+        result,
+        concat!(
+            "<form><fieldset>",
+            r#"<input type="hidden" name="csrf" value="abc"/><div class="dark"></div>"#,
+            "</fieldset></form>",
+            r#"<div class="contrast"></div>"#,
+            r#"<div class="dark"></div>"#,
+            r#"<div class="light"></div>"#,
+        )
+    );
+    assert_eq!(
+        html! { <CsrfField /> }.unwrap(),
+        r#"<input type="hidden" name="csrf"/>"#
+    );
+}
+
+#[test]
+fn enumerated_attributes() {
+    use html_codegen::enumerated::{Autocomplete, ButtonType, Method, Rel, Target, Unchecked};
+    use html_codegen::{html, html_format};
+    use pretty_assertions::assert_eq;
+
+    let new_tab = true;
+    let rels: Vec<Rel> = vec![];
+
+    let result = html! {
+        <form method={Method::Post} autocomplete={Autocomplete::Off}>
+            <a href="/" target={new_tab.then_some(Target::Blank)} rel={rels} />
+            <a href="/" target={Unchecked("preview")} rel={[Rel::Next, Rel::Nofollow]} />
+            <button type={ButtonType::Submit} formtarget={Target::Self_} />
+        </form>
+    }
+    .unwrap();
+
+    assert_eq!(
+        result,
+        concat!(
+            r#"<form method="post" autocomplete="off">"#,
+            r#"<a href="/" target="_blank"></a>"#,
+            r#"<a href="/" target="preview" rel="next nofollow"></a>"#,
+            r#"<button type="submit" formtarget="_self"></button>"#,
+            "</form>"
+        )
+    );
+
+    let formatted: String = html_format! { <form method={Method::Dialog} /> };
+    assert_eq!(formatted, r#"<form method="dialog" />"#);
+}
+
+#[cfg(not(feature = "strict-html"))]
+#[test]
+fn namespaced_attribute_names() {
+    use html_codegen::{html, html_format};
+    use pretty_assertions::assert_eq;
+
+    let open = "open = !open";
+    let disabled = true;
+
+    let result = html! {
+        <div x-data="{ open: false }" xml:lang="en" :class="open ? 'shown' : ''" class:md:flex=true>
+            <button @click={open} x-on:keyup.enter.prevent={open} hx-on::after-request="done()" />
+            <input x-model.number="count" disabled @input="save()" />
+        </div>
+    }
+    .unwrap();
+
+    assert_eq!(
+        result,
+        concat!(
+            r#"<div x-data="{ open: false }" xml:lang="en" :class="open ? &apos;shown&apos; : &apos;&apos;" class="md:flex">"#,
+            r#"<button @click="open = !open" x-on:keyup.enter.prevent="open = !open" hx-on::after-request="done()"></button>"#,
+            r#"<input x-model.number="count" disabled @input="save()"/>"#,
+            "</div>"
+        )
+    );
+
+    // The same words elsewhere in the macro don't matter
+    assert_eq!(
+        html! { <div><a x-on:click="go">"a"</a><p>"on :click"</p></div> }.unwrap(),
+        r#"<div><a x-on:click="go">a</a><p>on :click</p></div>"#
+    );
+
+    let formatted: String = html_format! { <svg xmlns:xlink="http://www.w3.org/1999/xlink"><image xlink:href={"#icon"} /></svg> };
+    assert_eq!(
+        formatted,
+        r##"<svg xmlns:xlink="http://www.w3.org/1999/xlink"><image xlink:href="#icon" /></svg>"##
+    );
+}
+
+#[test]
+fn raw_text_children() {
+    use html_codegen::{html, raw};
+    use pretty_assertions::assert_eq;
+
+    let config = r#"{"title": "<b>Tom & Jerry</b>", "end": "</SCRIPT>"}"#;
+
+    let result = html! {
+        <>
+            <head>
+                <script>"if (a && b) { run(); }"</script>
+                <script type="application/json">{config}</script>
+                <style>"p > a { color: red }"</style>
+                <script>{raw!("</scr")}{"ipt>"}</script>
+            </head>
+            <p>{"a && b"}</p>
+        </>
+    }
+    .unwrap();
+
+    assert_eq!(
+        result,
+        concat!(
+            "<head>",
+            "<script>if (a && b) { run(); }</script>",
+            r#"<script type="application/json">{"title": "<b>Tom & Jerry</b>", "end": "<\/SCRIPT>"}</script>"#,
+            "<style>p > a { color: red }</style>",
+            r"<script><\/script></script>",
+            "</head>",
+            "<p>a &amp;&amp; b</p>"
+        )
+    );
+}
+
+#[cfg(not(feature = "strict-html"))]
+#[test]
+fn xml_rendering() {
+    use html_codegen::xml::{Xml, XmlDeclaration};
+    use html_codegen::{html, rsx};
+    use pretty_assertions::assert_eq;
+
+    let title = "Tom & Jerry's <3";
+    let guid = "a\nb";
+    let tree = rsx! {
+        <>
+            <XmlDeclaration />
+            <rss version="2.0">
+                <channel>
+                    <title>{title}</title>
+                    <link>{"https://example.com/?a=1&b=2"}</link>
+                    <image />
+                    <script>{"a && b"}</script>
+                    <item guid={guid} hidden=true />
+                </channel>
+            </rss>
+        </>
+    };
+
+    assert_eq!(
+        html! { <Xml>{tree.clone()}</Xml> }.unwrap(),
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>"#,
+            r#"<rss version="2.0"><channel>"#,
+            "<title>Tom &amp; Jerry&apos;s &lt;3</title>",
+            "<link>https://example.com/?a=1&amp;b=2</link>",
+            "<image/>",
+            "<script>a &amp;&amp; b</script>",
+            r#"<item guid="a&#10;b" hidden="hidden"/>"#,
+            "</channel></rss>"
+        )
+    );
+
+    // The mode ends with the `Xml` element
+    assert_eq!(
+        html! { <><Xml><br /></Xml><br /><div /></> }.unwrap(),
+        "<br/><br/><div></div>"
+    );
+}
+
+#[test]
+fn pretty_rendering() {
+    use html_codegen::html::HTML5Doctype;
+    use html_codegen::{rsx, Render};
+    use pretty_assertions::assert_eq;
+
+    let page = || {
+        rsx! {
+            <>
+                <HTML5Doctype />
+                <html>
+                    <head>
+                        <title>{"Docs"}</title>
+                        <script>"if (a) {\n  b();\n}"</script>
+                    </head>
+                    <body>
+                        <main>
+                            <h1>"Hello, "<em>"world"</em></h1>
+                            <pre><code>"fn main() {}"</code><div>"  x"</div></pre>
+                            <textarea>"  a\n b"</textarea>
+                            <ul><li>"one"</li><li>"two"</li></ul>
+                        </main>
+                    </body>
+                </html>
+            </>
+        }
+    };
+
+    assert_eq!(
+        page().render_pretty("  ").unwrap(),
+        r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>Docs</title><script>if (a) {
+  b();
+}</script>
+  </head>
+  <body>
+    <main>
+      <h1>Hello, <em>world</em></h1>
+      <pre><code>fn main() {}</code><div>  x</div></pre><textarea>  a
+ b</textarea>
+      <ul>
+        <li>one</li>
+        <li>two</li>
+      </ul>
+    </main>
+  </body>
+</html>"#
+    );
+
+    // Only whitespace between block-level elements is added
+    let compact = page().render().unwrap();
+    let pretty = page().render_pretty("\t").unwrap();
+    assert_eq!(
+        pretty.replace(['\t', '\n'], ""),
+        compact.replace(['\t', '\n'], "")
+    );
+}
+
+#[test]
+fn minified_rendering() {
+    use html_codegen::xml::Xml;
+    use html_codegen::{rsx, Render, SimpleElement};
+    use pretty_assertions::assert_eq;
+
+    let rows = vec![("a", 1), ("b", 2)];
+    let message = "Hello,\n    world";
+    let custom_element = SimpleElement {
+        tag_name: "my-card",
+        attributes: None,
+        contents: Some(rsx! { <p>"Custom"</p> }),
+    };
+
+    let tree = rsx! {
+        <>
+            <table class="data">
+                <tbody>
+                    {rows.into_iter().map(|(key, value)| rsx! { <tr><td>{key}</td><td>{value}</td></tr> }).collect::<Vec<_>>()}
+                </tbody>
+            </table>
+            <p>{message}</p>
+            {"\n    "}
+            <a href="/"><p>"Link"</p></a>
+            <div><span><p>"Inline"</p></span>{custom_element}</div>
+            <dl><dt>"Term"</dt><dd>"Definition"</dd></dl>
+            <textarea>{message}</textarea>
+            <img src="/logo.png" alt="" />
+            <Xml><entry /></Xml>
+        </>
+    };
+
+    assert_eq!(
+        tree.render_minified().unwrap(),
+        concat!(
+            "<table class=data><tbody><tr><td>a<td>1<tr><td>b<td>2</table>",
+            "<p>Hello,\n    world</p> ",
+            "<a href=/><p>Link</p></a>",
+            "<div><span><p>Inline</p></span><my-card><p>Custom</p></my-card></div>",
+            "<dl><dt>Term<dd>Definition</dl>",
+            "<textarea>Hello,\n    world</textarea>",
+            r#"<img src=/logo.png alt="">"#,
+            "<entry/>"
+        )
+    );
+}
+
+#[test]
+fn io_rendering() {
+    use html_codegen::{component, html_write, rsx};
+    use pretty_assertions::assert_eq;
+    use std::io;
+
+    #[component]
+    fn Count<'a>(value: &'a str) -> Result<_, std::num::ParseIntError> {
+        let value: u32 = value.parse()?;
+        Ok(rsx! { <b>{value}</b> })
+    }
+
+    struct Full;
+
+    impl io::Write for Full {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::StorageFull, "disk full"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut output = Vec::new();
+    html_write!(&mut output, <p>{"a & b"}<Count value="3" /></p>).unwrap();
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "<p>a &amp; b<b>3</b></p>"
+    );
+
+    let error = html_write!(Full, <p>{"Hello"}</p>).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::StorageFull);
+    assert_eq!(error.to_string(), "disk full");
+
+    let error = html_write!(io::sink(), <p><Count value="three" /></p>).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::Other);
+    assert_eq!(
+        error.to_string(),
+        "failed to render `Count`: invalid digit found in string"
+    );
+}
+
+#[test]
+fn streaming_rendering() {
+    use futures_core::Stream;
+    use html_codegen::stream::CHUNK_SIZE;
+    use html_codegen::{component, rsx, Render};
+    use pretty_assertions::assert_eq;
+    use std::future::poll_fn;
+    use std::io;
+    use std::pin::Pin;
+
+    #[component]
+    fn Row(index: usize) -> Result<_, String> {
+        if index == 0 {
+            return Err("no row zero".to_string());
+        }
+        Ok(rsx! { <tr><td>{index}</td><td>{"ä € 𝄞 ".repeat(index % 7)}</td></tr> })
+    }
+
+    let table = |first: usize| {
+        let rows: Vec<_> = (first..=2000)
+            .map(|index| rsx! { <Row index={index} /> })
+            .collect();
+        rsx! { <table>{rows}</table> }
+    };
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+
+    runtime.block_on(async {
+        let mut stream = table(1).render_stream();
+        let mut chunks = Vec::new();
+        while let Some(chunk) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+            chunks.push(chunk.unwrap());
+        }
+
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= CHUNK_SIZE));
+        assert_eq!(chunks.concat(), table(1).render().unwrap().into_bytes());
+
+        let mut output = Vec::new();
+        tokio::spawn(async move {
+            table(1).render_to_async_write(&mut output).await.unwrap();
+            assert_eq!(output, table(1).render().unwrap().into_bytes());
+        })
+        .await
+        .unwrap();
+
+        let error = table(0)
+            .render_to_async_write(tokio::io::sink())
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::Other);
+        assert_eq!(error.to_string(), "failed to render `Row`: no row zero");
+    });
+}
+
+#[test]
+fn size_hints() {
+    use html_codegen::{component, rsx, Render};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Section<'a, Children: Render>(title: &'a str, children: Children) {
+        rsx! { <section><h2>{title}</h2>{children}</section> }
+    }
+
+    let page = || {
+        let rows: Vec<_> = (0..5000)
+            .map(|index| rsx! { <tr class="row"><td>{index}</td><td>{"cell"}</td><td><br /></td></tr> })
+            .collect();
+        rsx! {
+            <main>
+                <h1 id="title" hidden=true>"Report"</h1>
+                <table>{rows}</table>
+                <p>{Some(-42)}{None::<u8>}{String::from("end")}</p>
+            </main>
+        }
+    };
+
+    // Everything is hinted and nothing is escaped, so the page renders
+    // into a single allocation of its length, rather than growing 17 times
+    // from empty
+    let rendered = page().render().unwrap();
+    assert!(rendered.len() > 256 * 1024);
+    assert_eq!(page().size_hint(), rendered.len());
+    assert_eq!(rendered.capacity(), rendered.len());
+
+    // Escaped text is longer than its hint
+    assert_eq!(rsx! { <b>{"<>"}</b> }.size_hint(), "<b><></b>".len());
+
+    // A component's body only runs as it renders, so it hints its children
+    let section = rsx! { <Section title="Rows"><p>"Hello"</p></Section> };
+    assert_eq!(section.size_hint(), "<p>Hello</p>".len());
+}
+
+#[test]
+fn rendering_by_reference() {
+    use html_codegen::{component, html, rsx, use_context, Provide, Render, RenderRef};
+    use pretty_assertions::assert_eq;
+
+    #[component]
+    fn Link<'a>(href: &'a str, label: String) {
+        rsx! { <a href={href}>{label}</a> }
+    }
+
+    #[component]
+    fn Theme() {
+        rsx! { <b>{use_context::<&str>()}</b> }
+    }
+
+    #[derive(Debug)]
+    struct Counter(u32);
+
+    // A prop that isn't `Clone` only rules out rendering by reference
+    #[component]
+    fn Count(counter: Counter) {
+        rsx! { <i>{counter.0}</i> }
+    }
+
+    let nav = rsx! {
+        <nav>
+            <Link href="/" label={String::from("Home")} />
+            for page in 1..=2 {
+                <Link href="/page" label={format!("Page {page}")} />
+            }
+        </nav>
+    };
+    let nav_html =
+        r#"<nav><a href="/">Home</a><a href="/page">Page 1</a><a href="/page">Page 2</a></nav>"#;
+
+    let pages: Vec<_> = ["Posts", "About"]
+        .into_iter()
+        .map(|title| html! { <>{&nav}<h1>{title}</h1></> }.unwrap())
+        .collect();
+
+    assert_eq!(pages[0], format!("{nav_html}<h1>Posts</h1>"));
+    assert_eq!(pages[1], format!("{nav_html}<h1>About</h1>"));
+    assert_eq!((&nav).render().unwrap(), nav_html);
+    assert_eq!(nav.size_hint_ref(), nav.size_hint());
+    assert_eq!(nav.render().unwrap(), nav_html);
+
+    let themed = rsx! { <Provide value="dark"><Theme /></Provide> };
+    assert_eq!((&themed).render().unwrap(), "<b>dark</b>");
+    assert_eq!(themed.render().unwrap(), "<b>dark</b>");
+
+    let footer = [String::from("a"), String::from("<b>")];
+    assert_eq!((&footer[..]).render().unwrap(), "a&lt;b&gt;");
+
+    let count = rsx! { <Count counter={Counter(1)} /> };
+    assert_eq!(count.render().unwrap(), "<i>1</i>");
+}
+
+mod kaki {
+    // A simple HTML 5 doctype declaration
+    #[cfg(not(feature = "strict-html"))]
+    use html_codegen::html::HTML5Doctype;
+    #[cfg(not(feature = "strict-html"))]
+    use html_codegen::{
+        // A macro to create components
+        component,
+        // A macro to compose components in JSX fashion
+        rsx,
+        // A trait for custom components
+        Render,
+    };
+
+    // This can be any layout we want. Its htmx attributes and the `checked`
+    // on `<body>` are rejected by strict-html.
+    #[cfg(not(feature = "strict-html"))]
+    #[component]
+    fn Page<'a, Children: Render>(title: &'a str, children: Children) {
+        rsx! {
+          <>
+            <HTML5Doctype />
+            <html>
+              <head><title>{title}</title></head>
+              <body hx-boost={"true"} hx-swap={"innerHTML"} checked={false}>
+                {children}
+              </body>
+            </html>
+          </>
+        }
+    }
+
+    #[cfg(not(feature = "strict-html"))]
+    #[test]
+    fn test() {
+        use pretty_assertions::assert_eq;
+        let actual = html_codegen::html! {
+          <Page title={"Home"}>
+            {format!("Welcome, {}", "Gal")}
+          </Page>
+        }
+        .unwrap();
+        let expected = concat!(
+            "<!DOCTYPE html>",
+            "<html>",
+            "<head><title>Home</title></head>",
+            r#"<body hx-boost="true" hx-swap="innerHTML">"#,
+            "Welcome, Gal",
+            "</body>",
+            "</html>"
+        );
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn externals_test() {
+        use crate::other::ExternalPage;
+        use pretty_assertions::assert_eq;
+
+        let actual = html_codegen::html! {
+          <ExternalPage title={"Home"} subtitle={"Foo"}>
+            {format!("Welcome, {}", "Gal")}
+          </ExternalPage>
+        }
+        .unwrap();
+
+        let expected = concat!(
+            "<!DOCTYPE html>",
+            "<html>",
+            "<head><title>Home</title></head>",
+            "<body>",
+            "<h1>Foo</h1>",
+            "Welcome, Gal",
+            "</body>",
+            "</html>"
+        );
+        assert_eq!(actual, expected);
+    }
+}
+
+/// ## Other
+///
+/// Module for testing component visibility when imported from other modules.
 mod other {
     use html_codegen::html::HTML5Doctype;
     use html_codegen::{component, rsx, Render};
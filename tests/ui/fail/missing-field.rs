@@ -0,0 +1,16 @@
+use html_codegen::{html, rsx, Render};
+
+struct Link<'a> {
+    href: &'a str,
+    label: &'a str,
+}
+
+impl Render for Link<'_> {
+    fn render_into<W: std::fmt::Write>(self, writer: &mut W) -> std::fmt::Result {
+        rsx! { <a href={self.href}>{self.label}</a> }.render_into(writer)
+    }
+}
+
+fn main() {
+    html! { <Link href="/" /> };
+}
@@ -0,0 +1,10 @@
+use html_codegen::{component, html, rsx};
+
+#[component]
+fn Heading<'title>(title: &'title str, #[prop(default)] level: u8) {
+    rsx! { <h1 data-level={level}>{title}</h1> }
+}
+
+fn main() {
+    html! { <Heading level={2} /> };
+}
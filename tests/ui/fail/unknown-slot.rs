@@ -0,0 +1,15 @@
+use html_codegen::{component, html, rsx, Render};
+
+#[component]
+fn Card<Children: Render>(children: Children) {
+    rsx! { <div>{children}</div> }
+}
+
+fn main() {
+    html! {
+      <Card>
+        <slot:header>{"Title"}</slot:header>
+        {"Body"}
+      </Card>
+    };
+}
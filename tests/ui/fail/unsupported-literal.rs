@@ -0,0 +1,5 @@
+use html_codegen::html_format;
+
+fn main() {
+    let _ = html_format! { <p>{c"text"}</p> };
+}
@@ -0,0 +1,6 @@
+use html_codegen::html;
+
+fn main() {
+    let kind = String::from("submit");
+    let _ = html! { <button type={kind}>"Save"</button> };
+}
@@ -0,0 +1,11 @@
+use html_codegen::html;
+
+fn main() {
+    let _ = html! {
+        <form>
+            <input tpye="checkbox" />
+            <button type="sumbit">"Save"</button>
+            <spna>"Saved"</spna>
+        </form>
+    };
+}